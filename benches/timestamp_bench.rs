@@ -1,7 +1,9 @@
 //! Benchmarks for the timestamp module.
 
 use criterion::{Criterion, Throughput, criterion_group, criterion_main};
-use kiters::timestamp::{get_utc_formatter, get_utc_timestamp};
+use kiters::timestamp::{
+    CachedTimestamp, format_utc_into, format_utc_millis_into, get_utc_formatter, get_utc_timestamp,
+};
 use std::hint::black_box;
 use time::OffsetDateTime;
 
@@ -26,11 +28,39 @@ fn bench_timestamp(c: &mut Criterion) {
         b.iter(|| black_box(now.format(formatter).unwrap()))
     });
 
+    // Format pre-obtained time into a stack buffer (no allocation)
+    group.bench_function("timestamp/format_utc_into", |b| {
+        let now = OffsetDateTime::now_utc();
+        b.iter(|| black_box(format_utc_into(black_box(&now))))
+    });
+
+    group.bench_function("timestamp/format_utc_millis_into", |b| {
+        let now = OffsetDateTime::now_utc();
+        b.iter(|| black_box(format_utc_millis_into(black_box(&now))))
+    });
+
     // Full flow: get time + format
     group.bench_function("timestamp/get_utc_timestamp", |b| {
         b.iter(|| black_box(get_utc_timestamp()))
     });
 
+    // Full flow without allocation: get time + format into buffer
+    group.bench_function("timestamp/now_format_utc_into", |b| {
+        b.iter(|| black_box(format_utc_into(&OffsetDateTime::now_utc())))
+    });
+
+    // Cached: clock read + compare, re-format only on second change
+    group.bench_function("timestamp/cached_now", |b| {
+        let cache = CachedTimestamp::new();
+        b.iter(|| black_box(cache.now()))
+    });
+
+    // Cached: no clock read (ticker mode)
+    group.bench_function("timestamp/cached_last", |b| {
+        let cache = CachedTimestamp::new();
+        b.iter(|| black_box(cache.last()))
+    });
+
     // Compare with time crate's built-in RFC3339 formatting
     group.bench_function("time/rfc3339", |b| {
         use time::format_description::well_known::Rfc3339;
@@ -63,6 +93,14 @@ fn bench_timestamp_batch(c: &mut Criterion) {
         })
     });
 
+    group.bench_function("timestamp/format_utc_into", |b| {
+        b.iter(|| {
+            for _ in 0..1000 {
+                black_box(format_utc_into(&OffsetDateTime::now_utc()));
+            }
+        })
+    });
+
     group.finish();
 }
 
@@ -0,0 +1,166 @@
+//! Injectable time sources.
+//!
+//! Everything in the crate that needs "now" has a `*_with_clock` variant taking
+//! a [`Clock`], so tests can run against frozen or manually stepped time.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kiters::clock::{Clock, MockClock};
+//! use kiters::timestamp::get_utc_timestamp_with_clock;
+//! use time::{Duration, macros::datetime};
+//!
+//! let clock = MockClock::new(datetime!(2023-10-27 10:00:00 UTC));
+//! assert_eq!(get_utc_timestamp_with_clock(&clock), "2023-10-27T10:00:00Z");
+//!
+//! clock.advance(Duration::seconds(90));
+//! assert_eq!(get_utc_timestamp_with_clock(&clock), "2023-10-27T10:01:30Z");
+//! ```
+
+use std::sync::{Arc, Mutex};
+use time::{Duration, OffsetDateTime};
+
+/// A source of the current UTC time.
+pub trait Clock {
+    /// The current time. Implementations should return a UTC offset.
+    fn now_utc(&self) -> OffsetDateTime;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now_utc(&self) -> OffsetDateTime {
+        (**self).now_utc()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Box<C> {
+    fn now_utc(&self) -> OffsetDateTime {
+        (**self).now_utc()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now_utc(&self) -> OffsetDateTime {
+        (**self).now_utc()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// SystemClock
+// ---------------------------------------------------------------------------
+
+/// The real system clock (`OffsetDateTime::now_utc()`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now_utc(&self) -> OffsetDateTime {
+        OffsetDateTime::now_utc()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// FixedClock
+// ---------------------------------------------------------------------------
+
+/// A clock frozen at a single instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(OffsetDateTime);
+
+impl FixedClock {
+    /// Create a clock that always returns `at` (converted to UTC).
+    pub fn new(at: OffsetDateTime) -> Self {
+        Self(at.to_offset(time::UtcOffset::UTC))
+    }
+}
+
+impl Clock for FixedClock {
+    #[inline]
+    fn now_utc(&self) -> OffsetDateTime {
+        self.0
+    }
+}
+
+// ---------------------------------------------------------------------------
+// MockClock
+// ---------------------------------------------------------------------------
+
+/// A manually controlled clock. Thread-safe; share it by reference or `Arc`.
+///
+/// Time only moves when [`advance()`](Self::advance) or [`set()`](Self::set)
+/// is called. Negative durations step the clock backwards, which is useful for
+/// simulating wall-clock regressions.
+#[derive(Debug)]
+pub struct MockClock {
+    now: Mutex<OffsetDateTime>,
+}
+
+impl MockClock {
+    /// Create a clock starting at `start` (converted to UTC).
+    pub fn new(start: OffsetDateTime) -> Self {
+        Self {
+            now: Mutex::new(start.to_offset(time::UtcOffset::UTC)),
+        }
+    }
+
+    /// Move the clock by `by` (may be negative).
+    pub fn advance(&self, by: Duration) {
+        let mut now = self.now.lock().unwrap();
+        *now += by;
+    }
+
+    /// Jump the clock to `to` (converted to UTC).
+    pub fn set(&self, to: OffsetDateTime) {
+        *self.now.lock().unwrap() = to.to_offset(time::UtcOffset::UTC);
+    }
+}
+
+impl Clock for MockClock {
+    fn now_utc(&self) -> OffsetDateTime {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn test_system_clock_tracks_real_time() {
+        let before = OffsetDateTime::now_utc();
+        let now = SystemClock.now_utc();
+        let after = OffsetDateTime::now_utc();
+        assert!(before <= now && now <= after);
+    }
+
+    #[test]
+    fn test_fixed_clock_is_frozen_and_utc() {
+        let clock = FixedClock::new(datetime!(2023-10-27 12:00:00 +02:00));
+        assert_eq!(clock.now_utc(), datetime!(2023-10-27 10:00:00 UTC));
+        assert_eq!(clock.now_utc().offset(), time::UtcOffset::UTC);
+        assert_eq!(clock.now_utc(), clock.now_utc());
+    }
+
+    #[test]
+    fn test_mock_clock_advance_and_set() {
+        let clock = MockClock::new(datetime!(2023-10-27 10:00:00 UTC));
+        clock.advance(Duration::milliseconds(1500));
+        assert_eq!(clock.now_utc(), datetime!(2023-10-27 10:00:01.5 UTC));
+        clock.advance(Duration::seconds(-2));
+        assert_eq!(clock.now_utc(), datetime!(2023-10-27 09:59:59.5 UTC));
+        clock.set(datetime!(2000-01-01 00:00:00 UTC));
+        assert_eq!(clock.now_utc(), datetime!(2000-01-01 00:00:00 UTC));
+    }
+
+    #[test]
+    fn test_clock_through_pointers() {
+        let clock = Arc::new(FixedClock::new(datetime!(2023-10-27 10:00:00 UTC)));
+        let dynamic: Box<dyn Clock> = Box::new(SystemClock);
+        fn read(c: impl Clock) -> OffsetDateTime {
+            c.now_utc()
+        }
+        assert_eq!(read(&clock), read(Arc::clone(&clock)));
+        assert_eq!(dynamic.now_utc().offset(), time::UtcOffset::UTC);
+    }
+}
@@ -7,7 +7,13 @@
 //! - [`timestamp`]: Utilities for working with timestamps (specifically UTC formatted strings).
 //! - [`request_id`]: Fast request ID generator using sequential counter mapped to base64-like string.
 //! - [`eid`]: External ID system with prefix and UUID bytes encoded in base36.
+//! - [`clock`]: Injectable time sources (system, fixed, and manually advanced mock clocks).
+//!
+//! ## Feature flags
+//!
+//! - `tz`: named IANA time zone conversion in [`timestamp`], with an embedded tz database.
 
+pub mod clock;
 pub mod eid;
 pub mod request_id;
 pub mod timestamp;
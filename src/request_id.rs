@@ -5,6 +5,31 @@
 //! - **11 characters** (66 bits, captures a full `u64` — the 11th character uses only
 //!   16 of 64 alphabet positions since only 4 bits remain)
 //!
+//! # Node-aware IDs
+//!
+//! A plain generator is unique only within one process. For uniqueness across
+//! replicas, [`RequestIdGenerator::with_node`] (or `with_node_from_env` /
+//! `with_node_from_host`) embeds a node ID in the top quarter of the ID's bits,
+//! leaving the rest for the counter:
+//!
+//! | Width | ID bits | Node bits              | Counter bits                 |
+//! |-------|---------|------------------------|------------------------------|
+//! | 6     | 36      | 27..36 (9, 512 nodes)  | 0..27 (~134 million per node)|
+//! | 11    | 64      | 48..64 (16, 65536)     | 0..48 (~2.8 * 10^14)         |
+//!
+//! In general the node takes `bits / 4` bits, where `bits = min(6 * N, 64)`.
+//! The counter wraps within its field. Mixed generators mix the combined
+//! value, and a seeded key only ever alters the counter field, so generators
+//! on distinct nodes never collide, whatever their seeds.
+//!
+//! # Seeded generators
+//!
+//! `new()` always starts at 1, so each restart replays the same IDs.
+//! [`RequestIdGenerator::with_random_seed`] (OS randomness) or
+//! [`RequestIdGenerator::with_seed`] (explicit, reproducible) derive a
+//! starting counter and, for mixed generators, a per-instance key from a
+//! seed. [`RequestIdGenerator::decode_id`] inverts a generator's own IDs.
+//!
 //! # Example
 //!
 //! ```rust
@@ -21,13 +46,66 @@
 //! // Free functions
 //! let id6 = encode_request_id(12345);       // [u8; 6]
 //! let id11 = encode_request_id_wide(12345); // [u8; 11]
+//!
+//! // Decoding back to the counter
+//! assert_eq!(kiters::request_id::decode_request_id(&id6), Ok(12345));
+//!
+//! // Validated value type
+//! let rid = generator.next_request_id();
+//! let parsed: kiters::request_id::RequestId = rid.to_string().parse().unwrap();
+//! assert_eq!(parsed, rid);
 //! ```
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
 use std::sync::atomic::{AtomicU64, Ordering};
+use uuid::Uuid;
 
 /// URL-safe alphabet (64 characters = 6 bits per character)
 const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
 
+/// Reverse lookup for [`ALPHABET`]: character to 6-bit value, `0xFF` if absent.
+const DECODE: [u8; 256] = {
+    let mut table = [0xFF; 256];
+    let mut i = 0;
+    while i < ALPHABET.len() {
+        table[ALPHABET[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+};
+
+/// Errors produced when decoding a request ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequestIdError {
+    /// Input is not 6 or 11 characters long (or not the width required).
+    InvalidLength(usize),
+    /// Input contains a character outside the request ID alphabet.
+    InvalidChar(char),
+    /// The 11th character of a wide ID encodes bits beyond the 64-bit counter.
+    Overflow,
+    /// Node ID does not fit the node field of the ID width.
+    NodeOutOfRange(u64),
+    /// Node ID environment variable is missing or not an unsigned integer.
+    InvalidNode,
+}
+
+impl fmt::Display for RequestIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => write!(f, "invalid request ID length {len}"),
+            Self::InvalidChar(c) => write!(f, "invalid request ID character: '{c}'"),
+            Self::Overflow => write!(f, "request ID exceeds 64 bits"),
+            Self::NodeOutOfRange(node) => write!(f, "node ID {node} out of range"),
+            Self::InvalidNode => write!(f, "invalid node ID"),
+        }
+    }
+}
+
+impl std::error::Error for RequestIdError {}
+
 /// Internal base64 encoder: extracts 6 bits per character, LSB first.
 #[inline]
 fn encode_base64<const N: usize>(n: u64) -> [u8; N] {
@@ -46,16 +124,88 @@ fn encode_base64<const N: usize>(n: u64) -> [u8; N] {
     buf
 }
 
-/// splitmix64 mixing function — deterministic bijection on u64.
+/// Number of counter bits an `N`-character ID holds: 6 per character, capped at 64.
 #[inline]
-fn splitmix64(n: u64) -> u64 {
-    let mut x = n.wrapping_mul(0x9e3779b97f4a7c15);
-    x ^= x >> 30;
-    x = x.wrapping_mul(0xbf58476d1ce4e5b9);
-    x ^= x >> 27;
+const fn id_bits<const N: usize>() -> u32 {
+    if N * 6 >= 64 { 64 } else { (N * 6) as u32 }
+}
+
+/// Width-aware splitmix64: the same multiply/xorshift steps, confined to the
+/// low `bits` bits with shifts scaled to the width.
+///
+/// Each step is a bijection on `bits`-bit values (odd multipliers are
+/// invertible modulo any power of two), so the whole function is a
+/// permutation of `0..2^bits` — mixed IDs collide exactly when sequential ones
+/// do. At 64 bits it is identical to splitmix64.
+#[inline]
+const fn mix_bits(n: u64, bits: u32) -> u64 {
+    let mask = u64::MAX >> (64 - bits);
+    let mut x = (n & mask).wrapping_mul(0x9e3779b97f4a7c15) & mask;
+    x ^= x >> (bits * 30 / 64);
+    x = x.wrapping_mul(0xbf58476d1ce4e5b9) & mask;
+    x ^= x >> (bits * 27 / 64);
     x
 }
 
+/// Inverse of [`mix_bits`] for the same `bits`.
+#[inline]
+fn unmix_bits(n: u64, bits: u32) -> u64 {
+    let mask = u64::MAX >> (64 - bits);
+    let mut x = unxorshift(n & mask, bits * 27 / 64);
+    // Modular inverses of the multipliers above; also inverses modulo 2^bits.
+    x = x.wrapping_mul(0x96de1b173f119089) & mask;
+    x = unxorshift(x, bits * 30 / 64);
+    x.wrapping_mul(0xf1de83e19937733d) & mask
+}
+
+/// Inverse of `x ^= x >> shift` for `shift > 0`.
+#[inline]
+fn unxorshift(x: u64, shift: u32) -> u64 {
+    let mut y = x;
+    let mut t = x >> shift;
+    while t != 0 {
+        y ^= t;
+        t >>= shift;
+    }
+    y
+}
+
+/// Mix a counter into an `N`-character ID's bit space.
+#[inline]
+fn mix<const N: usize>(n: u64) -> u64 {
+    mix_bits(n, id_bits::<N>())
+}
+
+/// Recover the counter from a mixed `N`-character ID's value.
+#[inline]
+fn unmix<const N: usize>(n: u64) -> u64 {
+    unmix_bits(n, id_bits::<N>())
+}
+
+/// Internal base64 decoder, the inverse of [`encode_base64`].
+#[inline]
+fn decode_base64<const N: usize>(id: &[u8; N]) -> Result<u64, RequestIdError> {
+    const {
+        assert!(
+            N <= 11,
+            "N > 11 would shift past u64 width (11 * 6 = 66 >= 64)"
+        )
+    }
+    let mut n = 0u64;
+    for (i, &c) in id.iter().enumerate() {
+        let v = DECODE[c as usize];
+        if v == 0xFF {
+            return Err(RequestIdError::InvalidChar(c as char));
+        }
+        // Only 4 bits remain for the 11th character.
+        if i * 6 + 6 > 64 && v >> (64 - i * 6) != 0 {
+            return Err(RequestIdError::Overflow);
+        }
+        n |= (v as u64) << (i * 6);
+    }
+    Ok(n)
+}
+
 /// Encode a u64 into a 6-character ASCII string.
 ///
 /// Uses 6 bits per character (masked with 0x3F = 63) to index into a 64-char alphabet.
@@ -75,15 +225,77 @@ pub fn encode_request_id_wide(n: u64) -> [u8; 11] {
 }
 
 /// Encode with mixing for random-looking output (still deterministic), 6 chars.
+///
+/// The mixer is a permutation of the 36-bit ID space, so distinct counters
+/// below 2^36 always yield distinct IDs.
 #[inline]
 pub fn encode_request_id_mixed(n: u64) -> [u8; 6] {
-    encode_base64(splitmix64(n))
+    encode_base64(mix::<6>(n))
 }
 
 /// Encode with mixing for random-looking output (still deterministic), 11 chars.
 #[inline]
 pub fn encode_request_id_mixed_wide(n: u64) -> [u8; 11] {
-    encode_base64(splitmix64(n))
+    encode_base64(mix::<11>(n))
+}
+
+/// Decode a 6-character ID back to its counter value.
+///
+/// Only the low 36 bits survive encoding, so counters past 2^36 decode to
+/// their value modulo 2^36.
+#[inline]
+pub fn decode_request_id(id: &[u8; 6]) -> Result<u64, RequestIdError> {
+    decode_base64(id)
+}
+
+/// Decode an 11-character ID back to its counter value.
+#[inline]
+pub fn decode_request_id_wide(id: &[u8; 11]) -> Result<u64, RequestIdError> {
+    decode_base64(id)
+}
+
+/// Decode a 6-character mixed ID back to its counter value (modulo 2^36).
+#[inline]
+pub fn decode_request_id_mixed(id: &[u8; 6]) -> Result<u64, RequestIdError> {
+    decode_base64(id).map(unmix::<6>)
+}
+
+/// Decode an 11-character mixed ID back to its counter value.
+#[inline]
+pub fn decode_request_id_mixed_wide(id: &[u8; 11]) -> Result<u64, RequestIdError> {
+    decode_base64(id).map(unmix::<11>)
+}
+
+/// Decode a 6- or 11-character ID string back to its counter value.
+///
+/// # Example
+///
+/// ```
+/// use kiters::request_id::{decode_request_id_str, encode_request_id_wide, as_str};
+///
+/// assert_eq!(decode_request_id_str("BAAAAA"), Ok(1));
+/// let id = encode_request_id_wide(u64::MAX);
+/// assert_eq!(decode_request_id_str(as_str(&id)), Ok(u64::MAX));
+/// ```
+pub fn decode_request_id_str(s: &str) -> Result<u64, RequestIdError> {
+    if let Ok(id) = <&[u8; 6]>::try_from(s.as_bytes()) {
+        decode_request_id(id)
+    } else if let Ok(id) = <&[u8; 11]>::try_from(s.as_bytes()) {
+        decode_request_id_wide(id)
+    } else {
+        Err(RequestIdError::InvalidLength(s.len()))
+    }
+}
+
+/// Decode a 6- or 11-character mixed ID string back to its counter value.
+pub fn decode_request_id_mixed_str(s: &str) -> Result<u64, RequestIdError> {
+    if let Ok(id) = <&[u8; 6]>::try_from(s.as_bytes()) {
+        decode_request_id_mixed(id)
+    } else if let Ok(id) = <&[u8; 11]>::try_from(s.as_bytes()) {
+        decode_request_id_mixed_wide(id)
+    } else {
+        Err(RequestIdError::InvalidLength(s.len()))
+    }
 }
 
 /// Convert encoded bytes to &str (infallible — all bytes are ASCII).
@@ -93,23 +305,151 @@ pub fn as_str<const N: usize>(id: &[u8; N]) -> &str {
     unsafe { std::str::from_utf8_unchecked(id) }
 }
 
+/// A validated request ID of `N` characters (6 by default, or 11).
+///
+/// Derefs to `&str` and serializes as a string. Ordering compares the
+/// encoded characters, which is not counter order (IDs encode the low bits
+/// first); decode to the counter to order by sequence.
+///
+/// # Example
+///
+/// ```
+/// use kiters::request_id::{RequestId, WideRequestId};
+///
+/// let id: RequestId = "BAAAAA".parse().unwrap();
+/// assert_eq!(&*id, "BAAAAA");
+/// assert!(id.starts_with('B'));
+///
+/// assert!("BAA+AA".parse::<RequestId>().is_err());
+/// assert!("BAAAAA".parse::<WideRequestId>().is_err());
+/// ```
+///
+/// Other widths do not parse:
+///
+/// ```compile_fail
+/// let id = "AAAAAAAAAAAA".parse::<kiters::request_id::RequestId<12>>();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestId<const N: usize = 6>([u8; N]);
+
+/// Wide (11-character) request ID.
+pub type WideRequestId = RequestId<11>;
+
+impl<const N: usize> RequestId<N> {
+    /// The ID as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        as_str(&self.0)
+    }
+
+    /// The encoded bytes.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Consume the ID, returning the encoded bytes.
+    #[inline]
+    pub fn into_bytes(self) -> [u8; N] {
+        self.0
+    }
+}
+
+impl<const N: usize> Deref for RequestId<N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for RequestId<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Debug for RequestId<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RequestId").field(&self.as_str()).finish()
+    }
+}
+
+impl<const N: usize> fmt::Display for RequestId<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> FromStr for RequestId<N> {
+    type Err = RequestIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const { assert!(N == 6 || N == 11, "request IDs are 6 or 11 characters") }
+        let bytes: [u8; N] = s
+            .as_bytes()
+            .try_into()
+            .map_err(|_| RequestIdError::InvalidLength(s.len()))?;
+        decode_base64(&bytes)?;
+        Ok(Self(bytes))
+    }
+}
+
+impl<const N: usize> Serialize for RequestId<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for RequestId<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(RequestIdVisitor)
+    }
+}
+
+/// Parses borrowed strings in place, without allocating.
+struct RequestIdVisitor<const N: usize>;
+
+impl<const N: usize> de::Visitor<'_> for RequestIdVisitor<N> {
+    type Value = RequestId<N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a {N}-character request ID")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        s.parse().map_err(E::custom)
+    }
+}
+
 /// Thread-safe request ID generator.
 ///
 /// `N` is the output width in characters: 6 (default, 36 bits) or 11 (66 bits, full u64).
 pub struct RequestIdGenerator<const N: usize = 6> {
     counter: AtomicU64,
     mixed: bool,
+    node: Option<u64>,
+    key: u64,
 }
 
 /// Wide (11-character) request ID generator capturing all 64 bits.
 pub type WideRequestIdGenerator = RequestIdGenerator<11>;
 
 impl<const N: usize> RequestIdGenerator<N> {
+    /// Bits of the node field in node-aware IDs of this width.
+    pub const NODE_BITS: u32 = id_bits::<N>() / 4;
+
+    /// Bits of the counter field in node-aware IDs of this width.
+    pub const COUNTER_BITS: u32 = id_bits::<N>() - Self::NODE_BITS;
+
     /// Create a new generator starting at 1.
     pub const fn new() -> Self {
         Self {
             counter: AtomicU64::new(1),
             mixed: false,
+            node: None,
+            key: 0,
         }
     }
 
@@ -118,20 +458,151 @@ impl<const N: usize> RequestIdGenerator<N> {
         Self {
             counter: AtomicU64::new(1),
             mixed: true,
+            node: None,
+            key: 0,
+        }
+    }
+
+    /// Embed `node` in every ID, making IDs unique across generators with
+    /// distinct nodes. See the [module docs](self#node-aware-ids) for the
+    /// bit layout.
+    ///
+    /// Fails with [`RequestIdError::NodeOutOfRange`] if `node` needs more than
+    /// [`NODE_BITS`](Self::NODE_BITS) bits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kiters::request_id::{RequestIdGenerator, decode_request_id};
+    ///
+    /// let a: RequestIdGenerator = RequestIdGenerator::new().with_node(1).unwrap();
+    /// let b: RequestIdGenerator = RequestIdGenerator::new().with_node(2).unwrap();
+    /// let (id_a, id_b) = (a.next_id(), b.next_id());
+    /// assert_ne!(id_a, id_b);
+    ///
+    /// let value = decode_request_id(&id_b).unwrap();
+    /// assert_eq!(RequestIdGenerator::<6>::split_node(value), (2, 1));
+    /// ```
+    pub const fn with_node(mut self, node: u64) -> Result<Self, RequestIdError> {
+        if node >> Self::NODE_BITS != 0 {
+            return Err(RequestIdError::NodeOutOfRange(node));
         }
+        self.node = Some(node);
+        Ok(self)
+    }
+
+    /// Start the counter at a value derived from `seed` and, for mixed
+    /// generators, key the mixer with it.
+    ///
+    /// Distinct seeds give unrelated sequences; the same seed reproduces the
+    /// same IDs. Plain (unmixed) generators stay sequential from the seeded
+    /// start.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kiters::request_id::RequestIdGenerator;
+    ///
+    /// static IDS: RequestIdGenerator = RequestIdGenerator::new_mixed().with_seed(0x5eed);
+    ///
+    /// let id = IDS.next_id();
+    /// assert_eq!(IDS.decode_id(&id).unwrap() + 1, IDS.decode_id(&IDS.next_id()).unwrap());
+    /// ```
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.counter = AtomicU64::new(mix_bits(seed, 64));
+        self.key = mix_bits(seed ^ 0x6a09e667f3bcc909, 64);
+        self
+    }
+
+    /// Like [`with_seed`](Self::with_seed), seeding from OS randomness so
+    /// each process starts at an unpredictable point.
+    pub fn with_random_seed(self) -> Self {
+        let (hi, lo) = Uuid::new_v4().as_u64_pair();
+        self.with_seed(hi ^ lo.rotate_left(32))
+    }
+
+    /// Like [`with_node`](Self::with_node), reading the node ID from the
+    /// environment variable `var` (e.g. a StatefulSet ordinal).
+    pub fn with_node_from_env(self, var: &str) -> Result<Self, RequestIdError> {
+        let value = std::env::var(var).map_err(|_| RequestIdError::InvalidNode)?;
+        self.with_node(parse_node(&value)?)
+    }
+
+    /// Like [`with_node`](Self::with_node), deriving the node ID by hashing
+    /// the hostname and process ID.
+    ///
+    /// Hashed node IDs can collide: with 9 node bits (6-character IDs), two
+    /// of 20 replicas share a node about 30% of the time. Prefer explicit
+    /// node IDs for 6-character IDs.
+    pub fn with_node_from_host(self) -> Self {
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for b in hostname().bytes() {
+            hash = (hash ^ b as u64).wrapping_mul(0x100000001b3);
+        }
+        hash ^= std::process::id() as u64;
+        let node = mix_bits(hash, 64) >> (64 - Self::NODE_BITS);
+        Self {
+            node: Some(node),
+            ..self
+        }
+    }
+
+    /// The node ID embedded in generated IDs, if any.
+    pub const fn node(&self) -> Option<u64> {
+        self.node
+    }
+
+    /// Split a node-aware ID's decoded value into `(node, counter)`.
+    pub const fn split_node(value: u64) -> (u64, u64) {
+        let counter_mask = (1 << Self::COUNTER_BITS) - 1;
+        (
+            (value >> Self::COUNTER_BITS) & ((1 << Self::NODE_BITS) - 1),
+            value & counter_mask,
+        )
     }
 
     /// Generate the next request ID.
     #[inline]
     pub fn next_id(&self) -> [u8; N] {
-        let n = self.counter.fetch_add(1, Ordering::Relaxed);
+        let mut n = self.counter.fetch_add(1, Ordering::Relaxed);
+        if let Some(node) = self.node {
+            n = node << Self::COUNTER_BITS | n & ((1 << Self::COUNTER_BITS) - 1);
+        }
         if self.mixed {
-            encode_base64(splitmix64(n))
+            encode_base64(mix::<N>(n ^ (self.key & self.key_mask())))
         } else {
             encode_base64(n)
         }
     }
 
+    /// Decode an ID issued by this generator back to the value it encoded:
+    /// the counter (modulo the width), combined with the node if node-aware
+    /// (see [`split_node`](Self::split_node)). Undoes mixing and the seeded
+    /// key.
+    pub fn decode_id(&self, id: &[u8; N]) -> Result<u64, RequestIdError> {
+        let value = decode_base64(id)?;
+        if self.mixed {
+            Ok(unmix::<N>(value) ^ (self.key & self.key_mask()))
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Bits the seeded key may flip: the counter field when node-aware, so
+    /// the node survives into the mixer unchanged, else the whole ID.
+    const fn key_mask(&self) -> u64 {
+        match self.node {
+            Some(_) => (1 << Self::COUNTER_BITS) - 1,
+            None => u64::MAX >> (64 - id_bits::<N>()),
+        }
+    }
+
+    /// Generate the next request ID as a [`RequestId`].
+    #[inline]
+    pub fn next_request_id(&self) -> RequestId<N> {
+        RequestId(self.next_id())
+    }
+
     /// Generate next ID as a String.
     #[inline]
     pub fn next_id_string(&self) -> String {
@@ -146,6 +617,22 @@ impl<const N: usize> Default for RequestIdGenerator<N> {
     }
 }
 
+/// Parse a decimal node ID, ignoring surrounding whitespace.
+fn parse_node(s: &str) -> Result<u64, RequestIdError> {
+    s.trim().parse().map_err(|_| RequestIdError::InvalidNode)
+}
+
+/// Best-effort hostname without extra dependencies.
+fn hostname() -> String {
+    ["/proc/sys/kernel/hostname", "/etc/hostname"]
+        .iter()
+        .find_map(|path| std::fs::read_to_string(path).ok())
+        .or_else(|| std::env::var("HOSTNAME").ok())
+        .or_else(|| std::env::var("COMPUTERNAME").ok())
+        .map(|h| h.trim().to_owned())
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -253,6 +740,325 @@ mod tests {
         assert_ne!(plain, mixed);
     }
 
+    // --- Decoding tests ---
+
+    #[test]
+    fn test_decode_roundtrip() {
+        for n in [0, 1, 63, 64, 12345, (1 << 36) - 1] {
+            assert_eq!(decode_request_id(&encode_request_id(n)), Ok(n));
+        }
+        for n in [0, 1, 12345, 1 << 40, u64::MAX] {
+            assert_eq!(decode_request_id_wide(&encode_request_id_wide(n)), Ok(n));
+            assert_eq!(
+                decode_request_id_mixed_wide(&encode_request_id_mixed_wide(n)),
+                Ok(n)
+            );
+        }
+        // 6-character IDs keep only the low 36 bits.
+        assert_eq!(decode_request_id(&encode_request_id(1 << 36 | 7)), Ok(7));
+    }
+
+    #[test]
+    fn test_decode_generator_ids() {
+        let generator = WideRequestIdGenerator::new_mixed();
+        for expected in 1..=100 {
+            let id = generator.next_id();
+            assert_eq!(decode_request_id_mixed_str(as_str(&id)), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            decode_request_id_str("BAAAA"),
+            Err(RequestIdError::InvalidLength(5))
+        );
+        assert_eq!(
+            decode_request_id_str("BAA+AA"),
+            Err(RequestIdError::InvalidChar('+'))
+        );
+        assert_eq!(
+            decode_request_id_str("AAAAAAAAAAQ"),
+            Err(RequestIdError::Overflow)
+        );
+        assert_eq!(
+            decode_request_id_mixed_str("BAAAAAAA"),
+            Err(RequestIdError::InvalidLength(8))
+        );
+    }
+
+    #[test]
+    fn test_mix_64_is_splitmix64() {
+        fn splitmix64(n: u64) -> u64 {
+            let mut x = n.wrapping_mul(0x9e3779b97f4a7c15);
+            x ^= x >> 30;
+            x = x.wrapping_mul(0xbf58476d1ce4e5b9);
+            x ^= x >> 27;
+            x
+        }
+        for n in [0, 1, 2, 0xdead_beef, u64::MAX] {
+            assert_eq!(mix::<11>(n), splitmix64(n));
+            assert_eq!(unmix::<11>(splitmix64(n)), n);
+        }
+    }
+
+    #[test]
+    fn test_mixed_uniqueness() {
+        use std::collections::HashSet;
+        let mut seen = HashSet::new();
+        for i in 0..1_000_000u64 {
+            let id = encode_request_id_mixed(i);
+            assert!(seen.insert(id), "collision at {}", i);
+        }
+    }
+
+    #[test]
+    fn test_mixed_decode_roundtrip_6() {
+        let generator: RequestIdGenerator = RequestIdGenerator::new_mixed();
+        for expected in 1..=100 {
+            let id = generator.next_id();
+            assert_eq!(decode_request_id_mixed(&id), Ok(expected));
+            assert_eq!(decode_request_id_mixed_str(as_str(&id)), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_mix_small_widths_are_permutations() {
+        // Exhaustive over the full bit space for 1-3 characters.
+        fn check<const N: usize>() {
+            let size = 1u64 << id_bits::<N>();
+            let mut seen = vec![false; size as usize];
+            for n in 0..size {
+                let m = mix::<N>(n);
+                assert!(m < size && !seen[m as usize], "collision at {n} (N = {N})");
+                seen[m as usize] = true;
+                assert_eq!(unmix::<N>(m), n);
+            }
+        }
+        check::<1>();
+        check::<2>();
+        check::<3>();
+    }
+
+    // --- RequestId tests ---
+
+    #[test]
+    fn test_request_id_from_generator() {
+        let generator: RequestIdGenerator = RequestIdGenerator::new();
+        let id = generator.next_request_id();
+        assert_eq!(id.as_str(), "BAAAAA");
+        assert_eq!(id.len(), 6);
+        assert_eq!(id.to_string(), "BAAAAA");
+        assert_eq!(format!("{id:?}"), "RequestId(\"BAAAAA\")");
+        assert_eq!(id.into_bytes(), *b"BAAAAA");
+
+        let wide = WideRequestIdGenerator::new_mixed().next_request_id();
+        assert_eq!(decode_request_id_mixed_wide(wide.as_bytes()), Ok(1));
+    }
+
+    #[test]
+    fn test_request_id_parse() {
+        let id: RequestId = "ab-_09".parse().unwrap();
+        assert_eq!(&*id, "ab-_09");
+        assert_eq!(
+            "ab-_0".parse::<RequestId>(),
+            Err(RequestIdError::InvalidLength(5))
+        );
+        assert_eq!(
+            "ab-_0!".parse::<RequestId>(),
+            Err(RequestIdError::InvalidChar('!'))
+        );
+        assert_eq!(
+            "AAAAAAAAAA_".parse::<WideRequestId>(),
+            Err(RequestIdError::Overflow)
+        );
+        assert!("AAAAAAAAAAP".parse::<WideRequestId>().is_ok());
+    }
+
+    #[test]
+    fn test_request_id_serde() {
+        let id: RequestId = "BAAAAA".parse().unwrap();
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"BAAAAA\"");
+        assert_eq!(serde_json::from_str::<RequestId>(&json).unwrap(), id);
+        assert!(serde_json::from_str::<RequestId>("\"BAAAA\"").is_err());
+        assert!(serde_json::from_str::<WideRequestId>("\"BAAAAA\"").is_err());
+        assert!(serde_json::from_str::<RequestId>("42").is_err());
+
+        let value = serde_json::Value::String("AAAAAAAAAAP".to_owned());
+        let wide: WideRequestId = serde_json::from_value(value).unwrap();
+        assert_eq!(&*wide, "AAAAAAAAAAP");
+    }
+
+    #[test]
+    fn test_request_id_hash_and_ord() {
+        use std::collections::{BTreeSet, HashSet};
+        let generator: RequestIdGenerator = RequestIdGenerator::new_mixed();
+        let ids: Vec<RequestId> = (0..1000).map(|_| generator.next_request_id()).collect();
+        assert_eq!(ids.iter().collect::<HashSet<_>>().len(), 1000);
+        let sorted: Vec<_> = ids
+            .iter()
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        assert!(sorted.windows(2).all(|w| w[0].as_str() < w[1].as_str()));
+    }
+
+    // --- Node-aware tests ---
+
+    #[test]
+    fn test_node_layout() {
+        assert_eq!(RequestIdGenerator::<6>::NODE_BITS, 9);
+        assert_eq!(RequestIdGenerator::<6>::COUNTER_BITS, 27);
+        assert_eq!(WideRequestIdGenerator::NODE_BITS, 16);
+        assert_eq!(WideRequestIdGenerator::COUNTER_BITS, 48);
+
+        let generator = WideRequestIdGenerator::new().with_node(0xabcd).unwrap();
+        let value = decode_request_id_wide(&generator.next_id()).unwrap();
+        assert_eq!(value, 0xabcd << 48 | 1);
+        assert_eq!(WideRequestIdGenerator::split_node(value), (0xabcd, 1));
+    }
+
+    #[test]
+    fn test_node_out_of_range() {
+        assert!(RequestIdGenerator::<6>::new().with_node(511).is_ok());
+        assert!(matches!(
+            RequestIdGenerator::<6>::new().with_node(512),
+            Err(RequestIdError::NodeOutOfRange(512))
+        ));
+    }
+
+    #[test]
+    fn test_nodes_do_not_collide() {
+        use std::collections::HashSet;
+        let mut seen = HashSet::new();
+        for node in 0..8 {
+            for mixed in [false, true] {
+                let base = if mixed {
+                    RequestIdGenerator::<6>::new_mixed()
+                } else {
+                    RequestIdGenerator::<6>::new()
+                };
+                let generator = base.with_node(node).unwrap();
+                for _ in 0..1000 {
+                    let id = generator.next_id();
+                    let value = if mixed {
+                        decode_request_id_mixed(&id).unwrap()
+                    } else {
+                        decode_request_id(&id).unwrap()
+                    };
+                    assert!(seen.insert((mixed, id)));
+                    assert_eq!(RequestIdGenerator::<6>::split_node(value).0, node);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_node_from_env() {
+        assert_eq!(parse_node(" 42 \n"), Ok(42));
+        assert_eq!(parse_node("0"), Ok(0));
+        assert_eq!(parse_node(""), Err(RequestIdError::InvalidNode));
+        assert_eq!(parse_node("-1"), Err(RequestIdError::InvalidNode));
+        assert_eq!(parse_node("pod-3"), Err(RequestIdError::InvalidNode));
+        assert!(matches!(
+            RequestIdGenerator::<6>::new().with_node_from_env("KITERS_TEST_NODE_ID_UNSET"),
+            Err(RequestIdError::InvalidNode)
+        ));
+    }
+
+    #[test]
+    fn test_node_from_host_is_stable_and_in_range() {
+        let a = RequestIdGenerator::<6>::new().with_node_from_host();
+        let b = RequestIdGenerator::<6>::new().with_node_from_host();
+        assert_eq!(a.node(), b.node());
+        assert!(a.node().unwrap() < 512);
+    }
+
+    // --- Seeded tests ---
+
+    #[test]
+    fn test_unseeded_is_unchanged() {
+        static IDS: RequestIdGenerator = RequestIdGenerator::new();
+        assert_eq!(as_str(&IDS.next_id()), "BAAAAA");
+        let mixed = RequestIdGenerator::<6>::new_mixed();
+        assert_eq!(mixed.next_id(), encode_request_id_mixed(1));
+    }
+
+    #[test]
+    fn test_seed_is_reproducible() {
+        let a = RequestIdGenerator::<6>::new_mixed().with_seed(7);
+        let b = RequestIdGenerator::<6>::new_mixed().with_seed(7);
+        let c = RequestIdGenerator::<6>::new_mixed().with_seed(8);
+        let (ia, ib, ic) = (a.next_id(), b.next_id(), c.next_id());
+        assert_eq!(ia, ib);
+        assert_ne!(ia, ic);
+        assert_ne!(ia, encode_request_id_mixed(1));
+    }
+
+    #[test]
+    fn test_random_seed_differs_per_instance() {
+        let a = WideRequestIdGenerator::new().with_random_seed();
+        let b = WideRequestIdGenerator::new().with_random_seed();
+        assert_ne!(a.next_id(), b.next_id());
+        // Plain generators remain sequential from the seeded start.
+        let first = a.decode_id(&a.next_id()).unwrap();
+        assert_eq!(a.decode_id(&a.next_id()), Ok(first.wrapping_add(1)));
+    }
+
+    #[test]
+    fn test_seeded_decode_roundtrip() {
+        for mixed in [false, true] {
+            let base = if mixed {
+                RequestIdGenerator::<6>::new_mixed()
+            } else {
+                RequestIdGenerator::<6>::new()
+            };
+            let generator = base.with_seed(0xdead_beef).with_node(3).unwrap();
+            let start = generator.decode_id(&generator.next_id()).unwrap();
+            for i in 1..100 {
+                let value = generator.decode_id(&generator.next_id()).unwrap();
+                let (node, counter) = RequestIdGenerator::<6>::split_node(value);
+                assert_eq!(node, 3);
+                let (_, start_counter) = RequestIdGenerator::<6>::split_node(start);
+                assert_eq!(counter, (start_counter + i) & ((1 << 27) - 1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_seeded_nodes_do_not_collide() {
+        use std::collections::HashSet;
+        type Narrow = RequestIdGenerator<2>;
+        // 8 nodes with different seeds, each through a full counter cycle,
+        // must tile the whole 12-bit ID space.
+        let mut seen = HashSet::new();
+        for node in 0..1 << Narrow::NODE_BITS {
+            let generator = Narrow::new_mixed()
+                .with_seed(node ^ 0x5eed)
+                .with_node(node)
+                .unwrap();
+            for _ in 0..1 << Narrow::COUNTER_BITS {
+                let id = generator.next_id();
+                assert!(seen.insert(id));
+                let value = generator.decode_id(&id).unwrap();
+                assert_eq!(Narrow::split_node(value).0, node);
+            }
+        }
+        assert_eq!(seen.len(), 1 << 12);
+    }
+
+    #[test]
+    fn test_seeded_mixed_uniqueness() {
+        use std::collections::HashSet;
+        let generator = RequestIdGenerator::<6>::new_mixed().with_seed(42);
+        let mut seen = HashSet::new();
+        for _ in 0..100_000 {
+            assert!(seen.insert(generator.next_id()));
+        }
+    }
+
     #[test]
     fn test_as_str_works_for_both_widths() {
         let id6 = encode_request_id(99);
@@ -263,3 +1069,35 @@ mod tests {
         assert_eq!(s11.len(), 11);
     }
 }
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn mixed_6_is_bijective(n in 0u64..1 << 36) {
+            let id = encode_request_id_mixed(n);
+            prop_assert_eq!(decode_request_id_mixed(&id), Ok(n));
+        }
+
+        #[test]
+        fn mixed_6_distinct_counters_distinct_ids(a in 0u64..1 << 36, b in 0u64..1 << 36) {
+            prop_assume!(a != b);
+            prop_assert_ne!(encode_request_id_mixed(a), encode_request_id_mixed(b));
+        }
+
+        #[test]
+        fn mixed_wide_is_bijective(n: u64) {
+            let id = encode_request_id_mixed_wide(n);
+            prop_assert_eq!(decode_request_id_mixed_wide(&id), Ok(n));
+        }
+
+        #[test]
+        fn plain_roundtrip(n: u64) {
+            prop_assert_eq!(decode_request_id(&encode_request_id(n)), Ok(n & ((1 << 36) - 1)));
+            prop_assert_eq!(decode_request_id_wide(&encode_request_id_wide(n)), Ok(n));
+        }
+    }
+}
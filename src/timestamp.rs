@@ -1,4 +1,177 @@
-use time::{OffsetDateTime, format_description::FormatItem, macros::format_description};
+//! UTC timestamp utilities built around the canonical `YYYY-MM-DDTHH:MM:SSZ` format.
+
+mod basic;
+mod cached;
+mod duration;
+mod epoch;
+mod hlc;
+mod http;
+mod lenient;
+mod literal;
+mod monotonic;
+mod partition;
+mod relative;
+mod rfc3339;
+mod scan;
+pub mod serde;
+#[cfg(feature = "tz")]
+mod zone;
+
+pub use basic::{
+    format_utc_basic_into, format_utc_basic_millis_into, get_utc_basic_formatter,
+    get_utc_basic_formatter_millis, get_utc_timestamp_basic, get_utc_timestamp_basic_millis,
+    get_utc_timestamp_basic_millis_with_clock, get_utc_timestamp_basic_with_clock, parse_utc_basic,
+};
+pub use cached::{CachedTimestamp, Ticker};
+pub use duration::{IsoDuration, Steps, UtcInterval};
+pub use epoch::{
+    EpochUnit, epoch_str_to_utc_timestamp, epoch_to_utc_timestamp, parse_epoch, parse_epoch_str,
+    to_epoch, utc_timestamp_to_epoch,
+};
+pub use hlc::{HlcTimestamp, HybridLogicalClock};
+pub use http::{
+    format_http_date, format_http_date_into, get_http_date, get_http_date_formatter,
+    get_http_date_with_clock, http_date_to_utc_timestamp, parse_http_date,
+    parse_http_date_with_clock, utc_timestamp_to_http_date,
+};
+pub use lenient::{
+    DateTimeSeparator, LenientTimestamp, ParseMode, TimestampVariant, ZoneDesignator, canonicalize,
+    parse_lenient,
+};
+pub use literal::{parse_utc_timestamp_const, utc};
+pub use monotonic::{MonotonicTimestamp, MonotonicTimestamps, Precision};
+pub use partition::{Bucket, Buckets, PartitionStyle, Partitioner};
+pub use relative::{RelativeFormatter, RelativeStyle};
+pub use rfc3339::{format_rfc3339, normalize_to_utc, parse_rfc3339};
+pub use scan::{
+    TimestampMatch, TimestampScanner, canonicalize_timestamps, rewrite_timestamps, scan_timestamps,
+};
+#[cfg(feature = "tz")]
+pub use zone::{Disambiguation, LocalResult, NamedZone};
+
+use crate::clock::{Clock, SystemClock};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use time::{
+    Date, Month, OffsetDateTime, Time, UtcOffset, format_description::FormatItem,
+    macros::format_description,
+};
+
+/// Length in bytes of the canonical `YYYY-MM-DDTHH:MM:SSZ` representation.
+const UTC_TIMESTAMP_LEN: usize = 20;
+
+// ---------------------------------------------------------------------------
+// Error
+// ---------------------------------------------------------------------------
+
+/// Errors produced when parsing or converting timestamps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimestampError {
+    /// Input does not have the exact expected length (contains the actual length).
+    InvalidLength(usize),
+    /// Input does not match any of the accepted layouts.
+    UnrecognizedFormat,
+    /// A fixed separator (e.g. `-`, `T`, `:` or `Z`) is missing at the given byte index.
+    InvalidSeparator {
+        /// Byte index of the offending character.
+        index: usize,
+        /// The separator expected at that position.
+        expected: char,
+    },
+    /// Year is not four ASCII digits.
+    InvalidYear,
+    /// Month is not two ASCII digits in `01..=12` (or, for HTTP-dates, not a
+    /// three-letter English month name).
+    InvalidMonth,
+    /// Day is not two ASCII digits valid for the given year and month.
+    InvalidDay,
+    /// Hour is not two ASCII digits in `00..=23`.
+    InvalidHour,
+    /// Minute is not two ASCII digits in `00..=59`.
+    InvalidMinute,
+    /// Second is not two ASCII digits in `00..=59` (leap seconds are rejected).
+    InvalidSecond,
+    /// Fractional seconds are missing digits, too long, or not ASCII digits.
+    InvalidFraction,
+    /// Weekday name is unknown or does not match the date.
+    InvalidWeekday,
+    /// UTC offset is missing, malformed, or not expressible in the target format.
+    InvalidOffset,
+    /// ISO 8601 duration is malformed, or unsuitable for the operation.
+    InvalidDuration,
+    /// ISO 8601 interval is malformed, or its end precedes its start.
+    InvalidInterval,
+    /// Duration has year or month components, which have no fixed length.
+    NominalDuration,
+    /// Hybrid logical clock counter is not four lowercase hex digits.
+    InvalidLogical,
+    /// Hybrid logical clock node ID is not four lowercase hex digits.
+    InvalidNode,
+    /// Hybrid logical clock counter would exceed `u16::MAX` within one millisecond.
+    LogicalOverflow,
+    /// Remote hybrid logical clock timestamp is further ahead than the allowed drift.
+    ClockDrift,
+    /// Input is not a numeric Unix epoch.
+    InvalidEpoch,
+    /// Input has no `Z` or offset and strict parsing refuses to assume UTC.
+    MissingOffset,
+    /// Time zone name is not in the tz database.
+    UnknownZone,
+    /// Local time falls in a DST gap and does not exist in the zone.
+    NonexistentLocalTime,
+    /// Local time falls in a DST overlap and occurs twice in the zone.
+    AmbiguousLocalTime,
+    /// Value lies outside the range representable in the canonical format
+    /// (years `0000..=9999`).
+    OutOfRange,
+}
+
+impl fmt::Display for TimestampError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => write!(f, "invalid length {len}"),
+            Self::UnrecognizedFormat => write!(f, "unrecognized timestamp format"),
+            Self::InvalidSeparator { index, expected } => {
+                write!(f, "expected '{expected}' at byte {index}")
+            }
+            Self::InvalidYear => write!(f, "invalid year"),
+            Self::InvalidMonth => write!(f, "invalid month"),
+            Self::InvalidDay => write!(f, "invalid day"),
+            Self::InvalidHour => write!(f, "invalid hour"),
+            Self::InvalidMinute => write!(f, "invalid minute"),
+            Self::InvalidSecond => write!(f, "invalid second"),
+            Self::InvalidFraction => write!(f, "invalid fractional seconds"),
+            Self::InvalidWeekday => write!(f, "invalid weekday"),
+            Self::InvalidOffset => write!(f, "invalid UTC offset"),
+            Self::InvalidDuration => write!(f, "invalid ISO 8601 duration"),
+            Self::InvalidInterval => write!(f, "invalid ISO 8601 interval"),
+            Self::InvalidLogical => write!(f, "invalid logical counter"),
+            Self::InvalidNode => write!(f, "invalid node id"),
+            Self::LogicalOverflow => write!(f, "logical counter overflow"),
+            Self::UnknownZone => write!(f, "unknown time zone"),
+            Self::NonexistentLocalTime => write!(f, "local time does not exist in time zone"),
+            Self::AmbiguousLocalTime => write!(f, "local time is ambiguous in time zone"),
+            Self::MissingOffset => write!(f, "missing UTC offset"),
+            Self::InvalidEpoch => write!(f, "invalid numeric epoch"),
+            Self::ClockDrift => write!(f, "remote clock exceeds maximum allowed drift"),
+            Self::NominalDuration => {
+                write!(
+                    f,
+                    "duration has year or month components of variable length"
+                )
+            }
+            Self::OutOfRange => write!(f, "timestamp out of range (years 0000-9999)"),
+        }
+    }
+}
+
+impl std::error::Error for TimestampError {}
+
+// ---------------------------------------------------------------------------
+// Formatting
+// ---------------------------------------------------------------------------
 
 /// Returns a static reference to the UTC date-time format description.
 ///
@@ -26,9 +199,476 @@ pub fn get_utc_formatter() -> &'static [FormatItem<'static>] {
 /// println!("Current time: {}", ts); // e.g., 2023-10-27T10:00:00Z
 /// ```
 pub fn get_utc_timestamp() -> String {
-    OffsetDateTime::now_utc()
-        .format(get_utc_formatter())
-        .unwrap()
+    get_utc_timestamp_with_clock(&SystemClock)
+}
+
+/// Like [`get_utc_timestamp()`], reading the time from `clock`.
+pub fn get_utc_timestamp_with_clock<C: Clock + ?Sized>(clock: &C) -> String {
+    clock.now_utc().format(get_utc_formatter()).unwrap()
+}
+
+/// Returns a static reference to the millisecond-precision UTC format description.
+///
+/// The format is `YYYY-MM-DDTHH:MM:SS.sssZ` (24 characters, always zero-padded).
+pub fn get_utc_formatter_millis() -> &'static [FormatItem<'static>] {
+    format_description!(
+        "[year]-[month padding:zero]-[day padding:zero]T[hour padding:zero]:[minute padding:zero]:[second padding:zero].[subsecond digits:3]Z"
+    )
+}
+
+/// Returns a static reference to the microsecond-precision UTC format description.
+///
+/// The format is `YYYY-MM-DDTHH:MM:SS.ssssssZ` (27 characters, always zero-padded).
+pub fn get_utc_formatter_micros() -> &'static [FormatItem<'static>] {
+    format_description!(
+        "[year]-[month padding:zero]-[day padding:zero]T[hour padding:zero]:[minute padding:zero]:[second padding:zero].[subsecond digits:6]Z"
+    )
+}
+
+/// Returns a static reference to the nanosecond-precision UTC format description.
+///
+/// The format is `YYYY-MM-DDTHH:MM:SS.sssssssssZ` (30 characters, always zero-padded).
+pub fn get_utc_formatter_nanos() -> &'static [FormatItem<'static>] {
+    format_description!(
+        "[year]-[month padding:zero]-[day padding:zero]T[hour padding:zero]:[minute padding:zero]:[second padding:zero].[subsecond digits:9]Z"
+    )
+}
+
+/// Returns the current UTC timestamp with millisecond precision.
+///
+/// The format is `YYYY-MM-DDTHH:MM:SS.sssZ`. The fraction is truncated, not
+/// rounded, so the value never refers to a later instant than "now".
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::get_utc_timestamp_millis;
+///
+/// let ts = get_utc_timestamp_millis();
+/// println!("Current time: {}", ts); // e.g., 2023-10-27T10:00:00.123Z
+/// ```
+pub fn get_utc_timestamp_millis() -> String {
+    get_utc_timestamp_millis_with_clock(&SystemClock)
+}
+
+/// Like [`get_utc_timestamp_millis()`], reading the time from `clock`.
+pub fn get_utc_timestamp_millis_with_clock<C: Clock + ?Sized>(clock: &C) -> String {
+    clock.now_utc().format(get_utc_formatter_millis()).unwrap()
+}
+
+/// Returns the current UTC timestamp with microsecond precision.
+///
+/// The format is `YYYY-MM-DDTHH:MM:SS.ssssssZ` (fraction truncated).
+pub fn get_utc_timestamp_micros() -> String {
+    get_utc_timestamp_micros_with_clock(&SystemClock)
+}
+
+/// Like [`get_utc_timestamp_micros()`], reading the time from `clock`.
+pub fn get_utc_timestamp_micros_with_clock<C: Clock + ?Sized>(clock: &C) -> String {
+    clock.now_utc().format(get_utc_formatter_micros()).unwrap()
+}
+
+/// Returns the current UTC timestamp with nanosecond precision.
+///
+/// The format is `YYYY-MM-DDTHH:MM:SS.sssssssssZ`.
+pub fn get_utc_timestamp_nanos() -> String {
+    get_utc_timestamp_nanos_with_clock(&SystemClock)
+}
+
+/// Like [`get_utc_timestamp_nanos()`], reading the time from `clock`.
+pub fn get_utc_timestamp_nanos_with_clock<C: Clock + ?Sized>(clock: &C) -> String {
+    clock.now_utc().format(get_utc_formatter_nanos()).unwrap()
+}
+
+// ---------------------------------------------------------------------------
+// Allocation-free formatting
+// ---------------------------------------------------------------------------
+
+/// Write `n` as exactly `width` zero-padded ASCII digits starting at `at`.
+#[inline]
+fn write_digits(buf: &mut [u8], at: usize, width: usize, mut n: u32) {
+    let mut i = at + width;
+    while i > at {
+        i -= 1;
+        buf[i] = b'0' + (n % 10) as u8;
+        n /= 10;
+    }
+}
+
+/// Shared encoder: writes `YYYY-MM-DDTHH:MM:SS`, an optional `.` plus
+/// `N - 21` fractional digits, and the trailing `Z`.
+#[inline]
+fn format_utc_buf<const N: usize>(dt: &OffsetDateTime) -> [u8; N] {
+    const {
+        assert!(
+            N == UTC_TIMESTAMP_LEN || (N > UTC_TIMESTAMP_LEN + 1 && N <= UTC_TIMESTAMP_LEN + 10),
+            "N must be 20 (seconds) or 22..=30 (1-9 fractional digits)"
+        )
+    }
+    let dt = dt.to_offset(UtcOffset::UTC);
+    let year = dt.year();
+    assert!(
+        (0..=9999).contains(&year),
+        "year {year} is outside the canonical range 0000..=9999"
+    );
+
+    let mut buf = [0u8; N];
+    write_digits(&mut buf, 0, 4, year as u32);
+    buf[4] = b'-';
+    write_digits(&mut buf, 5, 2, dt.month() as u32);
+    buf[7] = b'-';
+    write_digits(&mut buf, 8, 2, dt.day() as u32);
+    buf[10] = b'T';
+    write_digits(&mut buf, 11, 2, dt.hour() as u32);
+    buf[13] = b':';
+    write_digits(&mut buf, 14, 2, dt.minute() as u32);
+    buf[16] = b':';
+    write_digits(&mut buf, 17, 2, dt.second() as u32);
+
+    if N > UTC_TIMESTAMP_LEN {
+        let digits = N - UTC_TIMESTAMP_LEN - 1;
+        let fraction = dt.nanosecond() / 10u32.pow(9 - digits as u32);
+        buf[19] = b'.';
+        write_digits(&mut buf, 20, digits, fraction);
+    }
+    buf[N - 1] = b'Z';
+    buf
+}
+
+/// Format an `OffsetDateTime` as `YYYY-MM-DDTHH:MM:SSZ` into a fixed-size buffer.
+///
+/// Produces byte-for-byte the same output as [`get_utc_formatter()`] without heap
+/// allocation. Non-UTC inputs are converted to UTC first. The output is always
+/// ASCII, so `std::str::from_utf8` on it never fails.
+///
+/// # Panics
+///
+/// Panics if the UTC year is outside `0000..=9999`.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::format_utc_into;
+/// use time::macros::datetime;
+///
+/// let buf = format_utc_into(&datetime!(2023-10-27 10:00:00 UTC));
+/// assert_eq!(&buf, b"2023-10-27T10:00:00Z");
+/// ```
+#[inline]
+pub fn format_utc_into(dt: &OffsetDateTime) -> [u8; 20] {
+    format_utc_buf(dt)
+}
+
+/// Millisecond-precision variant of [`format_utc_into`]: `YYYY-MM-DDTHH:MM:SS.sssZ`.
+#[inline]
+pub fn format_utc_millis_into(dt: &OffsetDateTime) -> [u8; 24] {
+    format_utc_buf(dt)
+}
+
+/// Microsecond-precision variant of [`format_utc_into`]: `YYYY-MM-DDTHH:MM:SS.ssssssZ`.
+#[inline]
+pub fn format_utc_micros_into(dt: &OffsetDateTime) -> [u8; 27] {
+    format_utc_buf(dt)
+}
+
+/// Nanosecond-precision variant of [`format_utc_into`]: `YYYY-MM-DDTHH:MM:SS.sssssssssZ`.
+#[inline]
+pub fn format_utc_nanos_into(dt: &OffsetDateTime) -> [u8; 30] {
+    format_utc_buf(dt)
+}
+
+// ---------------------------------------------------------------------------
+// Parsing
+// ---------------------------------------------------------------------------
+
+/// Parse a fixed-width run of ASCII digits. Returns `None` on any non-digit.
+#[inline]
+const fn parse_digits(bytes: &[u8]) -> Option<u32> {
+    let mut n = 0u32;
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        n = n * 10 + (bytes[i] - b'0') as u32;
+        i += 1;
+    }
+    Some(n)
+}
+
+/// Parse the `len` digits starting at byte `at`; the const form of
+/// `parse_digits(&b[at..at + len])`.
+#[inline]
+const fn parse_digits_at(b: &[u8], at: usize, len: usize) -> Option<u32> {
+    parse_digits(b.split_at(at + len).0.split_at(at).1)
+}
+
+#[inline]
+const fn expect_separator(bytes: &[u8], index: usize, expected: u8) -> Result<(), TimestampError> {
+    if bytes[index] == expected {
+        Ok(())
+    } else {
+        Err(TimestampError::InvalidSeparator {
+            index,
+            expected: expected as char,
+        })
+    }
+}
+
+/// Parse `YYYY-MM-DD` starting at byte `at`. Caller guarantees `b.len() >= at + 10`.
+const fn parse_ymd(b: &[u8], at: usize) -> Result<Date, TimestampError> {
+    if let Err(e) = expect_separator(b, at + 4, b'-') {
+        return Err(e);
+    }
+    if let Err(e) = expect_separator(b, at + 7, b'-') {
+        return Err(e);
+    }
+    let Some(year) = parse_digits_at(b, at, 4) else {
+        return Err(TimestampError::InvalidYear);
+    };
+    let month = match parse_digits_at(b, at + 5, 2) {
+        Some(m @ 1..=12) => Month::January.nth_next(m as u8 - 1),
+        _ => return Err(TimestampError::InvalidMonth),
+    };
+    let Some(day) = parse_digits_at(b, at + 8, 2) else {
+        return Err(TimestampError::InvalidDay);
+    };
+    match Date::from_calendar_date(year as i32, month, day as u8) {
+        Ok(date) => Ok(date),
+        Err(_) => Err(TimestampError::InvalidDay),
+    }
+}
+
+/// Parse `HH:MM:SS` starting at byte `at`. Caller guarantees `b.len() >= at + 8`.
+const fn parse_hms(b: &[u8], at: usize) -> Result<Time, TimestampError> {
+    if let Err(e) = expect_separator(b, at + 2, b':') {
+        return Err(e);
+    }
+    if let Err(e) = expect_separator(b, at + 5, b':') {
+        return Err(e);
+    }
+    let hour = match parse_digits_at(b, at, 2) {
+        Some(h @ 0..24) => h,
+        _ => return Err(TimestampError::InvalidHour),
+    };
+    let minute = match parse_digits_at(b, at + 3, 2) {
+        Some(m @ 0..60) => m,
+        _ => return Err(TimestampError::InvalidMinute),
+    };
+    let second = match parse_digits_at(b, at + 6, 2) {
+        Some(s @ 0..60) => s,
+        _ => return Err(TimestampError::InvalidSecond),
+    };
+    // Components were range-checked above, so this cannot fail.
+    match Time::from_hms(hour as u8, minute as u8, second as u8) {
+        Ok(time) => Ok(time),
+        Err(_) => Err(TimestampError::InvalidSecond),
+    }
+}
+
+/// Convert 1-9 fractional-second digits into nanoseconds.
+fn parse_fraction(digits: &[u8]) -> Result<u32, TimestampError> {
+    if digits.is_empty() || digits.len() > 9 {
+        return Err(TimestampError::InvalidFraction);
+    }
+    let n = parse_digits(digits).ok_or(TimestampError::InvalidFraction)?;
+    Ok(n * 10u32.pow(9 - digits.len() as u32))
+}
+
+/// Parse `Z`, `z` or `±HH:MM`, which must make up the whole of `b`. With
+/// `lenient`, the basic forms `±HH` and `±HHMM` are accepted as well.
+fn parse_offset(b: &[u8], lenient: bool) -> Result<UtcOffset, TimestampError> {
+    let (sign, hh, mm) = match b {
+        [b'Z' | b'z'] => return Ok(UtcOffset::UTC),
+        [sign @ (b'+' | b'-'), h1, h2, b':', m1, m2] => (sign, [*h1, *h2], [*m1, *m2]),
+        [sign @ (b'+' | b'-'), h1, h2] if lenient => (sign, [*h1, *h2], [b'0', b'0']),
+        [sign @ (b'+' | b'-'), h1, h2, m1, m2] if lenient => (sign, [*h1, *h2], [*m1, *m2]),
+        _ => return Err(TimestampError::InvalidOffset),
+    };
+    let hours = parse_digits(&hh)
+        .filter(|&h| h < 24)
+        .ok_or(TimestampError::InvalidOffset)? as i8;
+    let minutes = parse_digits(&mm)
+        .filter(|&m| m < 60)
+        .ok_or(TimestampError::InvalidOffset)? as i8;
+    let (hours, minutes) = if *sign == b'-' {
+        (-hours, -minutes)
+    } else {
+        (hours, minutes)
+    };
+    UtcOffset::from_hms(hours, minutes, 0).map_err(|_| TimestampError::InvalidOffset)
+}
+
+/// Parse a canonical UTC timestamp (`YYYY-MM-DDTHH:MM:SSZ`) into an [`OffsetDateTime`].
+///
+/// This is the strict inverse of [`get_utc_formatter()`]: it accepts exactly the
+/// 20-character form the formatter produces and nothing else — no lowercase
+/// `t`/`z`, no fractional seconds, no numeric offsets.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::parse_utc_timestamp;
+///
+/// let dt = parse_utc_timestamp("2023-10-27T10:00:00Z").unwrap();
+/// assert_eq!(dt.unix_timestamp(), 1_698_400_800);
+/// ```
+pub fn parse_utc_timestamp(s: &str) -> Result<OffsetDateTime, TimestampError> {
+    parse_utc_timestamp_const(s)
+}
+
+// ---------------------------------------------------------------------------
+// UtcTimestamp
+// ---------------------------------------------------------------------------
+
+/// A UTC instant with second precision, displayed in the canonical format.
+///
+/// The wrapped [`OffsetDateTime`] is always normalized to UTC and truncated to
+/// whole seconds, so two values compare equal exactly when their canonical
+/// strings are equal, and ordering matches chronological (and lexicographic)
+/// order. Only years `0000..=9999` are representable, since the canonical
+/// format has a fixed four-digit year.
+///
+/// Serializes as the canonical string, not as a struct.
+///
+/// # Examples
+///
+/// ```
+/// use kiters::timestamp::UtcTimestamp;
+///
+/// let ts: UtcTimestamp = "2023-10-27T10:00:00Z".parse().unwrap();
+/// assert_eq!(ts.to_string(), "2023-10-27T10:00:00Z");
+/// assert!(ts < UtcTimestamp::now());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcTimestamp(OffsetDateTime);
+
+impl UtcTimestamp {
+    /// The current time, truncated to whole seconds.
+    pub fn now() -> Self {
+        Self::now_with_clock(&SystemClock)
+    }
+
+    /// The current time according to `clock`, truncated to whole seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock reports a year outside `0000..=9999`.
+    pub fn now_with_clock<C: Clock + ?Sized>(clock: &C) -> Self {
+        Self::new(clock.now_utc()).expect("clock time outside the canonical range")
+    }
+
+    /// Create a `UtcTimestamp` from any `OffsetDateTime`.
+    ///
+    /// The value is converted to UTC and sub-second precision is discarded.
+    /// Fails with [`TimestampError::OutOfRange`] if the UTC year falls outside
+    /// `0000..=9999`.
+    pub fn new(dt: OffsetDateTime) -> Result<Self, TimestampError> {
+        let dt = dt
+            .checked_to_offset(UtcOffset::UTC)
+            .ok_or(TimestampError::OutOfRange)?;
+        let dt = truncate_to_second(dt);
+        if !(0..=9999).contains(&dt.year()) {
+            return Err(TimestampError::OutOfRange);
+        }
+        Ok(Self(dt))
+    }
+
+    /// Create a `UtcTimestamp` from seconds since the Unix epoch.
+    pub fn from_unix_timestamp(secs: i64) -> Result<Self, TimestampError> {
+        let dt =
+            OffsetDateTime::from_unix_timestamp(secs).map_err(|_| TimestampError::OutOfRange)?;
+        Self::new(dt)
+    }
+
+    /// Seconds since the Unix epoch.
+    pub fn unix_timestamp(&self) -> i64 {
+        self.0.unix_timestamp()
+    }
+
+    /// The underlying `OffsetDateTime` (always UTC, whole seconds).
+    pub fn as_offset_date_time(&self) -> OffsetDateTime {
+        self.0
+    }
+}
+
+#[inline]
+fn truncate_to_second(dt: OffsetDateTime) -> OffsetDateTime {
+    // Setting nanosecond to 0 is always valid.
+    dt.replace_nanosecond(0).unwrap()
+}
+
+impl TryFrom<OffsetDateTime> for UtcTimestamp {
+    type Error = TimestampError;
+
+    fn try_from(dt: OffsetDateTime) -> Result<Self, Self::Error> {
+        Self::new(dt)
+    }
+}
+
+impl From<UtcTimestamp> for OffsetDateTime {
+    fn from(ts: UtcTimestamp) -> Self {
+        ts.0
+    }
+}
+
+impl fmt::Display for UtcTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = self.0.format(get_utc_formatter()).map_err(|_| fmt::Error)?;
+        f.write_str(&s)
+    }
+}
+
+impl FromStr for UtcTimestamp {
+    type Err = TimestampError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_utc_timestamp(s).map(Self)
+    }
+}
+
+impl Serialize for UtcTimestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UtcTimestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("a canonical UTC timestamp"))
+    }
+}
+
+/// Deserializes a string-encoded `T` through its `FromStr` impl, parsing
+/// borrowed strings in place without allocating.
+struct FromStrVisitor<T> {
+    expecting: &'static str,
+    marker: PhantomData<T>,
+}
+
+impl<T> FromStrVisitor<T> {
+    const fn new(expecting: &'static str) -> Self {
+        Self {
+            expecting,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> de::Visitor<'_> for FromStrVisitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+        s.parse().map_err(E::custom)
+    }
 }
 
 #[cfg(test)]
@@ -73,4 +713,348 @@ mod tests {
         // Second
         assert!(ts[17..19].chars().all(|c| c.is_ascii_digit()));
     }
+
+    // --- Parsing tests ---
+
+    #[test]
+    fn test_parse_known_value() {
+        let dt = parse_utc_timestamp("2023-10-27T10:00:00Z").unwrap();
+        assert_eq!(dt.unix_timestamp(), 1_698_400_800);
+        assert_eq!(dt.offset(), time::UtcOffset::UTC);
+    }
+
+    #[test]
+    fn test_parse_roundtrip_now() {
+        let ts = get_utc_timestamp();
+        let dt = parse_utc_timestamp(&ts).unwrap();
+        assert_eq!(dt.format(get_utc_formatter()).unwrap(), ts);
+    }
+
+    #[test]
+    fn test_parse_leap_day() {
+        assert!(parse_utc_timestamp("2024-02-29T00:00:00Z").is_ok());
+        assert_eq!(
+            parse_utc_timestamp("2023-02-29T00:00:00Z"),
+            Err(TimestampError::InvalidDay)
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_length() {
+        assert_eq!(
+            parse_utc_timestamp("2023-10-27T10:00Z"),
+            Err(TimestampError::InvalidLength(17))
+        );
+        assert_eq!(
+            parse_utc_timestamp("2023-10-27T10:00:00.000Z"),
+            Err(TimestampError::InvalidLength(24))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_separators() {
+        assert_eq!(
+            parse_utc_timestamp("2023-10-27 10:00:00Z"),
+            Err(TimestampError::InvalidSeparator {
+                index: 10,
+                expected: 'T'
+            })
+        );
+        assert_eq!(
+            parse_utc_timestamp("2023-10-27T10:00:00z"),
+            Err(TimestampError::InvalidSeparator {
+                index: 19,
+                expected: 'Z'
+            })
+        );
+        assert_eq!(
+            parse_utc_timestamp("2023/10-27T10:00:00Z"),
+            Err(TimestampError::InvalidSeparator {
+                index: 4,
+                expected: '-'
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_components() {
+        assert_eq!(
+            parse_utc_timestamp("20x3-10-27T10:00:00Z"),
+            Err(TimestampError::InvalidYear)
+        );
+        assert_eq!(
+            parse_utc_timestamp("2023-13-27T10:00:00Z"),
+            Err(TimestampError::InvalidMonth)
+        );
+        assert_eq!(
+            parse_utc_timestamp("2023-00-27T10:00:00Z"),
+            Err(TimestampError::InvalidMonth)
+        );
+        assert_eq!(
+            parse_utc_timestamp("2023-10-32T10:00:00Z"),
+            Err(TimestampError::InvalidDay)
+        );
+        assert_eq!(
+            parse_utc_timestamp("2023-10-27T24:00:00Z"),
+            Err(TimestampError::InvalidHour)
+        );
+        assert_eq!(
+            parse_utc_timestamp("2023-10-27T10:60:00Z"),
+            Err(TimestampError::InvalidMinute)
+        );
+        assert_eq!(
+            parse_utc_timestamp("2023-10-27T10:00:60Z"),
+            Err(TimestampError::InvalidSecond)
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_sign_in_digits() {
+        assert_eq!(
+            parse_utc_timestamp("2023-+1-27T10:00:00Z"),
+            Err(TimestampError::InvalidMonth)
+        );
+    }
+
+    // --- UtcTimestamp tests ---
+
+    #[test]
+    fn test_utc_timestamp_display_parse_roundtrip() {
+        let ts: UtcTimestamp = "2023-10-27T10:00:00Z".parse().unwrap();
+        assert_eq!(ts.to_string(), "2023-10-27T10:00:00Z");
+        assert_eq!(ts.unix_timestamp(), 1_698_400_800);
+    }
+
+    #[test]
+    fn test_utc_timestamp_normalizes_offset_and_truncates() {
+        let dt = time::macros::datetime!(2023-10-27 12:00:00.999 +02:00);
+        let ts = UtcTimestamp::new(dt).unwrap();
+        assert_eq!(ts.to_string(), "2023-10-27T10:00:00Z");
+        assert_eq!(ts.as_offset_date_time().offset(), time::UtcOffset::UTC);
+        assert_eq!(ts.as_offset_date_time().nanosecond(), 0);
+    }
+
+    #[test]
+    fn test_utc_timestamp_equality_ignores_subseconds() {
+        let a = UtcTimestamp::new(time::macros::datetime!(2023-10-27 10:00:00.1 UTC)).unwrap();
+        let b = UtcTimestamp::new(time::macros::datetime!(2023-10-27 10:00:00.9 UTC)).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_utc_timestamp_ordering() {
+        let a: UtcTimestamp = "2023-10-27T09:59:59Z".parse().unwrap();
+        let b: UtcTimestamp = "2023-10-27T10:00:00Z".parse().unwrap();
+        assert!(a < b);
+        assert!(a.to_string() < b.to_string());
+    }
+
+    #[test]
+    fn test_utc_timestamp_out_of_range() {
+        let dt = time::macros::datetime!(-0001-01-01 00:00:00 UTC);
+        assert_eq!(UtcTimestamp::new(dt), Err(TimestampError::OutOfRange));
+        assert_eq!(
+            UtcTimestamp::from_unix_timestamp(i64::MAX),
+            Err(TimestampError::OutOfRange)
+        );
+
+        // The UTC conversion itself leaves the representable range.
+        assert_eq!(
+            UtcTimestamp::new(time::macros::datetime!(9999-12-31 23:00 -05:00)),
+            Err(TimestampError::OutOfRange)
+        );
+        assert_eq!(
+            UtcTimestamp::new(time::macros::datetime!(-9999-01-01 00:00 +01:00)),
+            Err(TimestampError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_utc_timestamp_serde_json() {
+        let ts: UtcTimestamp = "2023-10-27T10:00:00Z".parse().unwrap();
+        let json = serde_json::to_string(&ts).unwrap();
+        // Must be a JSON string, not an object
+        assert_eq!(json, "\"2023-10-27T10:00:00Z\"");
+        let ts2: UtcTimestamp = serde_json::from_str(&json).unwrap();
+        assert_eq!(ts, ts2);
+    }
+
+    #[test]
+    fn test_utc_timestamp_serde_rejects_non_canonical() {
+        assert!(serde_json::from_str::<UtcTimestamp>("\"2023-10-27 10:00:00Z\"").is_err());
+        let err = serde_json::from_str::<UtcTimestamp>("1698400800").unwrap_err();
+        assert!(
+            err.to_string().contains("a canonical UTC timestamp"),
+            "{err}"
+        );
+    }
+
+    // --- Sub-second precision tests ---
+
+    #[test]
+    fn test_subsecond_timestamp_lengths() {
+        assert_eq!(get_utc_timestamp_millis().len(), 24);
+        assert_eq!(get_utc_timestamp_micros().len(), 27);
+        assert_eq!(get_utc_timestamp_nanos().len(), 30);
+    }
+
+    #[test]
+    fn test_subsecond_timestamp_shape() {
+        for ts in [
+            get_utc_timestamp_millis(),
+            get_utc_timestamp_micros(),
+            get_utc_timestamp_nanos(),
+        ] {
+            assert_eq!(ts.chars().nth(10), Some('T'));
+            assert_eq!(ts.chars().nth(19), Some('.'));
+            assert!(ts.ends_with('Z'));
+            assert!(ts[20..ts.len() - 1].chars().all(|c| c.is_ascii_digit()));
+        }
+    }
+
+    #[test]
+    fn test_subsecond_zero_padded_and_truncated() {
+        let dt = time::macros::datetime!(2023-10-27 10:00:00.000_012_999 UTC);
+        assert_eq!(
+            dt.format(get_utc_formatter_millis()).unwrap(),
+            "2023-10-27T10:00:00.000Z"
+        );
+        assert_eq!(
+            dt.format(get_utc_formatter_micros()).unwrap(),
+            "2023-10-27T10:00:00.000012Z"
+        );
+        assert_eq!(
+            dt.format(get_utc_formatter_nanos()).unwrap(),
+            "2023-10-27T10:00:00.000012999Z"
+        );
+    }
+
+    #[test]
+    fn test_subsecond_sorts_lexicographically() {
+        let a = time::macros::datetime!(2023-10-27 10:00:00.9 UTC);
+        let b = time::macros::datetime!(2023-10-27 10:00:01.05 UTC);
+        let fa = a.format(get_utc_formatter_millis()).unwrap();
+        let fb = b.format(get_utc_formatter_millis()).unwrap();
+        assert!(fa < fb);
+    }
+
+    // --- Allocation-free formatting tests ---
+
+    #[test]
+    fn test_format_into_matches_formatters() {
+        let dt = time::macros::datetime!(2023-01-02 03:04:05.006_007_008 UTC);
+        let s = |b: &[u8]| std::str::from_utf8(b).unwrap().to_owned();
+        assert_eq!(
+            s(&format_utc_into(&dt)),
+            dt.format(get_utc_formatter()).unwrap()
+        );
+        assert_eq!(
+            s(&format_utc_millis_into(&dt)),
+            dt.format(get_utc_formatter_millis()).unwrap()
+        );
+        assert_eq!(
+            s(&format_utc_micros_into(&dt)),
+            dt.format(get_utc_formatter_micros()).unwrap()
+        );
+        assert_eq!(
+            s(&format_utc_nanos_into(&dt)),
+            dt.format(get_utc_formatter_nanos()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_format_into_converts_to_utc() {
+        let dt = time::macros::datetime!(2023-10-27 12:00:00 +02:00);
+        assert_eq!(&format_utc_into(&dt), b"2023-10-27T10:00:00Z");
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the canonical range")]
+    fn test_format_into_panics_out_of_range() {
+        let dt = time::macros::datetime!(-0001-01-01 00:00:00 UTC);
+        format_utc_into(&dt);
+    }
+
+    // --- Clock injection tests ---
+
+    #[test]
+    fn test_timestamp_with_fixed_clock() {
+        use crate::clock::FixedClock;
+        let clock = FixedClock::new(time::macros::datetime!(2023-10-27 10:00:00.123_456_789 UTC));
+        assert_eq!(get_utc_timestamp_with_clock(&clock), "2023-10-27T10:00:00Z");
+        assert_eq!(
+            get_utc_timestamp_millis_with_clock(&clock),
+            "2023-10-27T10:00:00.123Z"
+        );
+        assert_eq!(
+            get_utc_timestamp_micros_with_clock(&clock),
+            "2023-10-27T10:00:00.123456Z"
+        );
+        assert_eq!(
+            get_utc_timestamp_nanos_with_clock(&clock),
+            "2023-10-27T10:00:00.123456789Z"
+        );
+        assert_eq!(
+            UtcTimestamp::now_with_clock(&clock).to_string(),
+            "2023-10-27T10:00:00Z"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_with_mock_clock_steps() {
+        use crate::clock::MockClock;
+        let clock = MockClock::new(time::macros::datetime!(2023-12-31 23:59:59 UTC));
+        assert_eq!(get_utc_timestamp_with_clock(&clock), "2023-12-31T23:59:59Z");
+        clock.advance(time::Duration::SECOND);
+        assert_eq!(get_utc_timestamp_with_clock(&clock), "2024-01-01T00:00:00Z");
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // 0000-01-01T00:00:00Z ..= 9999-12-31T23:59:59Z
+    const MIN_UNIX: i64 = -62_167_219_200;
+    const MAX_UNIX: i64 = 253_402_300_799;
+
+    proptest! {
+        #[test]
+        fn format_parse_roundtrip(secs in MIN_UNIX..=MAX_UNIX) {
+            let dt = OffsetDateTime::from_unix_timestamp(secs).unwrap();
+            let s = dt.format(get_utc_formatter()).unwrap();
+            let parsed = parse_utc_timestamp(&s).unwrap();
+            prop_assert_eq!(parsed, dt);
+        }
+
+        #[test]
+        fn parse_never_panics(s in "\\PC{0,32}") {
+            let _ = parse_utc_timestamp(&s);
+        }
+
+        #[test]
+        fn utc_timestamp_serde_roundtrip(secs in MIN_UNIX..=MAX_UNIX) {
+            let ts = UtcTimestamp::from_unix_timestamp(secs).unwrap();
+            let json = serde_json::to_string(&ts).unwrap();
+            let ts2: UtcTimestamp = serde_json::from_str(&json).unwrap();
+            prop_assert_eq!(ts, ts2);
+        }
+
+        #[test]
+        fn utc_timestamp_order_matches_string_order(a in MIN_UNIX..=MAX_UNIX, b in MIN_UNIX..=MAX_UNIX) {
+            let ta = UtcTimestamp::from_unix_timestamp(a).unwrap();
+            let tb = UtcTimestamp::from_unix_timestamp(b).unwrap();
+            prop_assert_eq!(ta.cmp(&tb), ta.to_string().cmp(&tb.to_string()));
+        }
+
+        #[test]
+        fn format_into_matches_formatter(secs in MIN_UNIX..=MAX_UNIX, nanos in 0u32..1_000_000_000) {
+            let dt = OffsetDateTime::from_unix_timestamp(secs).unwrap().replace_nanosecond(nanos).unwrap();
+            let buf = format_utc_nanos_into(&dt);
+            prop_assert_eq!(std::str::from_utf8(&buf).unwrap(), dt.format(get_utc_formatter_nanos()).unwrap());
+            let buf = format_utc_into(&dt);
+            prop_assert_eq!(std::str::from_utf8(&buf).unwrap(), dt.format(get_utc_formatter()).unwrap());
+        }
+    }
 }
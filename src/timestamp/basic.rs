@@ -0,0 +1,308 @@
+//! ISO 8601 "basic" format (`YYYYMMDDTHHMMSSZ`) — the canonical timestamp
+//! without `-` and `:` separators, safe for filenames and object keys.
+
+use super::{TimestampError, expect_separator, parse_digits, parse_fraction, write_digits};
+use crate::clock::{Clock, SystemClock};
+use time::{
+    Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset,
+    format_description::FormatItem, macros::format_description,
+};
+
+/// Length in bytes of `YYYYMMDDTHHMMSSZ`.
+const UTC_BASIC_LEN: usize = 16;
+
+/// Returns a static reference to the basic-format UTC date-time description.
+///
+/// The format is `YYYYMMDDTHHMMSSZ` (16 characters).
+pub fn get_utc_basic_formatter() -> &'static [FormatItem<'static>] {
+    format_description!(
+        "[year][month padding:zero][day padding:zero]T[hour padding:zero][minute padding:zero][second padding:zero]Z"
+    )
+}
+
+/// Returns a static reference to the millisecond-precision basic-format description.
+///
+/// The format is `YYYYMMDDTHHMMSS.sssZ` (20 characters).
+pub fn get_utc_basic_formatter_millis() -> &'static [FormatItem<'static>] {
+    format_description!(
+        "[year][month padding:zero][day padding:zero]T[hour padding:zero][minute padding:zero][second padding:zero].[subsecond digits:3]Z"
+    )
+}
+
+/// Shared encoder for the basic layout, with `N - 17` fractional digits when
+/// `N > 16`.
+#[inline]
+fn format_utc_basic_buf<const N: usize>(dt: &OffsetDateTime) -> [u8; N] {
+    const {
+        assert!(
+            N == UTC_BASIC_LEN || (N > UTC_BASIC_LEN + 1 && N <= UTC_BASIC_LEN + 10),
+            "N must be 16 (seconds) or 18..=26 (1-9 fractional digits)"
+        )
+    }
+    let dt = dt.to_offset(UtcOffset::UTC);
+    let year = dt.year();
+    assert!(
+        (0..=9999).contains(&year),
+        "year {year} is outside the canonical range 0000..=9999"
+    );
+
+    let mut buf = [0u8; N];
+    write_digits(&mut buf, 0, 4, year as u32);
+    write_digits(&mut buf, 4, 2, dt.month() as u32);
+    write_digits(&mut buf, 6, 2, dt.day() as u32);
+    buf[8] = b'T';
+    write_digits(&mut buf, 9, 2, dt.hour() as u32);
+    write_digits(&mut buf, 11, 2, dt.minute() as u32);
+    write_digits(&mut buf, 13, 2, dt.second() as u32);
+    if N > UTC_BASIC_LEN {
+        let digits = N - UTC_BASIC_LEN - 1;
+        buf[15] = b'.';
+        write_digits(
+            &mut buf,
+            16,
+            digits,
+            dt.nanosecond() / 10u32.pow(9 - digits as u32),
+        );
+    }
+    buf[N - 1] = b'Z';
+    buf
+}
+
+/// Format an `OffsetDateTime` as `YYYYMMDDTHHMMSSZ` into a fixed-size buffer.
+///
+/// Non-UTC inputs are converted to UTC first.
+///
+/// # Panics
+///
+/// Panics if the UTC year is outside `0000..=9999`.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::format_utc_basic_into;
+/// use time::macros::datetime;
+///
+/// let buf = format_utc_basic_into(&datetime!(2023-10-27 10:00:00 UTC));
+/// assert_eq!(&buf, b"20231027T100000Z");
+/// ```
+#[inline]
+pub fn format_utc_basic_into(dt: &OffsetDateTime) -> [u8; 16] {
+    format_utc_basic_buf(dt)
+}
+
+/// Millisecond-precision variant of [`format_utc_basic_into`]: `YYYYMMDDTHHMMSS.sssZ`.
+#[inline]
+pub fn format_utc_basic_millis_into(dt: &OffsetDateTime) -> [u8; 20] {
+    format_utc_basic_buf(dt)
+}
+
+/// Returns the current UTC timestamp in basic format, e.g. `20231027T100000Z`.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::get_utc_timestamp_basic;
+///
+/// let name = format!("snapshot-{}.tar", get_utc_timestamp_basic());
+/// assert!(!name.contains(':'));
+/// ```
+pub fn get_utc_timestamp_basic() -> String {
+    get_utc_timestamp_basic_with_clock(&SystemClock)
+}
+
+/// Like [`get_utc_timestamp_basic()`], reading the time from `clock`.
+pub fn get_utc_timestamp_basic_with_clock<C: Clock + ?Sized>(clock: &C) -> String {
+    let buf = format_utc_basic_into(&clock.now_utc());
+    // All bytes written are ASCII.
+    String::from_utf8(buf.to_vec()).unwrap()
+}
+
+/// Returns the current UTC timestamp in basic format with milliseconds,
+/// e.g. `20231027T100000.123Z`.
+pub fn get_utc_timestamp_basic_millis() -> String {
+    get_utc_timestamp_basic_millis_with_clock(&SystemClock)
+}
+
+/// Like [`get_utc_timestamp_basic_millis()`], reading the time from `clock`.
+pub fn get_utc_timestamp_basic_millis_with_clock<C: Clock + ?Sized>(clock: &C) -> String {
+    let buf = format_utc_basic_millis_into(&clock.now_utc());
+    String::from_utf8(buf.to_vec()).unwrap()
+}
+
+/// Parse a basic-format UTC timestamp: `YYYYMMDDTHHMMSSZ`, optionally with a
+/// `.` and 1-9 fractional-second digits before the `Z`.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::parse_utc_basic;
+///
+/// let dt = parse_utc_basic("20231027T100000Z").unwrap();
+/// assert_eq!(dt.unix_timestamp(), 1_698_400_800);
+///
+/// let dt = parse_utc_basic("20231027T100000.250Z").unwrap();
+/// assert_eq!(dt.millisecond(), 250);
+/// ```
+pub fn parse_utc_basic(s: &str) -> Result<OffsetDateTime, TimestampError> {
+    let b = s.as_bytes();
+    let len = b.len();
+    if len != UTC_BASIC_LEN && !(UTC_BASIC_LEN + 2..=UTC_BASIC_LEN + 10).contains(&len) {
+        return Err(TimestampError::InvalidLength(len));
+    }
+
+    expect_separator(b, 8, b'T')?;
+    if len > UTC_BASIC_LEN {
+        expect_separator(b, 15, b'.')?;
+    }
+    expect_separator(b, len - 1, b'Z')?;
+
+    let year = parse_digits(&b[0..4]).ok_or(TimestampError::InvalidYear)?;
+    let month = parse_digits(&b[4..6])
+        .and_then(|m| Month::try_from(m as u8).ok())
+        .ok_or(TimestampError::InvalidMonth)?;
+    let day = parse_digits(&b[6..8]).ok_or(TimestampError::InvalidDay)?;
+    let hour = parse_digits(&b[9..11])
+        .filter(|&h| h < 24)
+        .ok_or(TimestampError::InvalidHour)?;
+    let minute = parse_digits(&b[11..13])
+        .filter(|&m| m < 60)
+        .ok_or(TimestampError::InvalidMinute)?;
+    let second = parse_digits(&b[13..15])
+        .filter(|&s| s < 60)
+        .ok_or(TimestampError::InvalidSecond)?;
+    let nanosecond = if len > UTC_BASIC_LEN {
+        parse_fraction(&b[16..len - 1])?
+    } else {
+        0
+    };
+
+    let date = Date::from_calendar_date(year as i32, month, day as u8)
+        .map_err(|_| TimestampError::InvalidDay)?;
+    // Components were range-checked above, so this cannot fail.
+    let time = Time::from_hms_nano(hour as u8, minute as u8, second as u8, nanosecond)
+        .map_err(|_| TimestampError::InvalidFraction)?;
+    Ok(PrimitiveDateTime::new(date, time).assume_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn test_format_basic() {
+        let dt = datetime!(2023-01-02 03:04:05.006_007_008 UTC);
+        assert_eq!(&format_utc_basic_into(&dt), b"20230102T030405Z");
+        assert_eq!(&format_utc_basic_millis_into(&dt), b"20230102T030405.006Z");
+        assert_eq!(
+            dt.format(get_utc_basic_formatter()).unwrap(),
+            "20230102T030405Z"
+        );
+        assert_eq!(
+            dt.format(get_utc_basic_formatter_millis()).unwrap(),
+            "20230102T030405.006Z"
+        );
+    }
+
+    #[test]
+    fn test_format_basic_converts_to_utc() {
+        let dt = datetime!(2023-10-27 12:00:00 +02:00);
+        assert_eq!(&format_utc_basic_into(&dt), b"20231027T100000Z");
+    }
+
+    #[test]
+    fn test_basic_with_clock() {
+        let clock = crate::clock::FixedClock::new(datetime!(2023-10-27 10:00:00.5 UTC));
+        assert_eq!(
+            get_utc_timestamp_basic_with_clock(&clock),
+            "20231027T100000Z"
+        );
+        assert_eq!(
+            get_utc_timestamp_basic_millis_with_clock(&clock),
+            "20231027T100000.500Z"
+        );
+        assert_eq!(get_utc_timestamp_basic().len(), 16);
+        assert_eq!(get_utc_timestamp_basic_millis().len(), 20);
+    }
+
+    #[test]
+    fn test_parse_basic() {
+        assert_eq!(
+            parse_utc_basic("20231027T100000Z"),
+            Ok(datetime!(2023-10-27 10:00:00 UTC))
+        );
+        assert_eq!(
+            parse_utc_basic("20231027T100000.1Z"),
+            Ok(datetime!(2023-10-27 10:00:00.1 UTC))
+        );
+        assert_eq!(
+            parse_utc_basic("20231027T100000.123456789Z"),
+            Ok(datetime!(2023-10-27 10:00:00.123_456_789 UTC))
+        );
+    }
+
+    #[test]
+    fn test_parse_basic_errors() {
+        assert_eq!(
+            parse_utc_basic("2023-10-27T10:00:00Z"),
+            Err(TimestampError::InvalidSeparator {
+                index: 8,
+                expected: 'T'
+            })
+        );
+        assert_eq!(
+            parse_utc_basic("20231027T100000.Z"),
+            Err(TimestampError::InvalidLength(17))
+        );
+        assert_eq!(
+            parse_utc_basic("20231027 100000Z"),
+            Err(TimestampError::InvalidSeparator {
+                index: 8,
+                expected: 'T'
+            })
+        );
+        assert_eq!(
+            parse_utc_basic("20231027T100000,5Z"),
+            Err(TimestampError::InvalidSeparator {
+                index: 15,
+                expected: '.'
+            })
+        );
+        assert_eq!(
+            parse_utc_basic("20231027T100000.5aZ"),
+            Err(TimestampError::InvalidFraction)
+        );
+        assert_eq!(
+            parse_utc_basic("20231327T100000Z"),
+            Err(TimestampError::InvalidMonth)
+        );
+        assert_eq!(
+            parse_utc_basic("20231027T100060Z"),
+            Err(TimestampError::InvalidSecond)
+        );
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn format_parse_roundtrip(secs in -62_167_219_200i64..=253_402_300_799, millis in 0u32..1000) {
+            let dt = OffsetDateTime::from_unix_timestamp(secs).unwrap();
+            let buf = format_utc_basic_into(&dt);
+            prop_assert_eq!(parse_utc_basic(std::str::from_utf8(&buf).unwrap()).unwrap(), dt);
+
+            let dt = dt.replace_millisecond(millis as u16).unwrap();
+            let buf = format_utc_basic_millis_into(&dt);
+            prop_assert_eq!(parse_utc_basic(std::str::from_utf8(&buf).unwrap()).unwrap(), dt);
+        }
+
+        #[test]
+        fn parse_never_panics(s in "\\PC{0,40}") {
+            let _ = parse_utc_basic(&s);
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! Coarse cached clock: reuses the last formatted second instead of
+//! re-formatting on every call.
+
+use super::format_utc_into;
+use crate::clock::{Clock, SystemClock};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering, fence};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Sentinel for "nothing cached yet".
+const UNSET: i64 = i64::MIN;
+
+/// Thread-safe cache of the canonical `YYYY-MM-DDTHH:MM:SSZ` timestamp.
+///
+/// The formatted bytes are published through a sequence lock built on atomics,
+/// so readers never block and never observe a torn value.
+///
+/// Two ways to use it, with different staleness guarantees:
+///
+/// - **Lazy** — [`now()`](Self::now) reads the system clock on every call and
+///   re-formats only when the second changes. The result is never stale; the
+///   saving is the formatting work, not the clock read.
+/// - **Ticker** — [`spawn_ticker()`](Self::spawn_ticker) refreshes the cache
+///   from a background thread and [`last()`](Self::last) returns the cached
+///   value without touching the clock. The result lags real time by at most the
+///   ticker interval plus thread scheduling delay, and is never ahead of it.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::CachedTimestamp;
+///
+/// static CLOCK: CachedTimestamp = CachedTimestamp::new();
+///
+/// let ts = CLOCK.now(); // [u8; 20]
+/// assert_eq!(ts.len(), 20);
+/// assert_eq!(ts[19], b'Z');
+/// ```
+pub struct CachedTimestamp {
+    seq: AtomicU64,
+    second: AtomicI64,
+    words: [AtomicU64; 3],
+}
+
+impl CachedTimestamp {
+    /// Create an empty cache. Usable in `static` items.
+    pub const fn new() -> Self {
+        Self {
+            seq: AtomicU64::new(0),
+            second: AtomicI64::new(UNSET),
+            words: [AtomicU64::new(0), AtomicU64::new(0), AtomicU64::new(0)],
+        }
+    }
+
+    /// Current timestamp, re-formatted only when the second has changed.
+    ///
+    /// Always reflects the current system time (to the second).
+    pub fn now(&self) -> [u8; 20] {
+        self.now_with_clock(&SystemClock)
+    }
+
+    /// Like [`now()`](Self::now), reading the time from `clock`.
+    pub fn now_with_clock<C: Clock + ?Sized>(&self, clock: &C) -> [u8; 20] {
+        let dt = clock.now_utc();
+        let second = dt.unix_timestamp();
+        if let Some((cached, buf)) = self.load()
+            && cached == second
+        {
+            return buf;
+        }
+        let buf = format_utc_into(&dt);
+        self.store(second, &buf);
+        buf
+    }
+
+    /// Last cached timestamp, without reading the clock.
+    ///
+    /// Intended for use with [`spawn_ticker()`](Self::spawn_ticker). If nothing
+    /// has been cached yet, this refreshes from the system clock.
+    pub fn last(&self) -> [u8; 20] {
+        match self.load() {
+            Some((second, buf)) if second != UNSET => buf,
+            _ => self.refresh(),
+        }
+    }
+
+    /// Read the system clock and publish a freshly formatted timestamp.
+    pub fn refresh(&self) -> [u8; 20] {
+        self.refresh_with_clock(&SystemClock)
+    }
+
+    /// Like [`refresh()`](Self::refresh), reading the time from `clock`.
+    pub fn refresh_with_clock<C: Clock + ?Sized>(&self, clock: &C) -> [u8; 20] {
+        let dt = clock.now_utc();
+        let buf = format_utc_into(&dt);
+        self.store(dt.unix_timestamp(), &buf);
+        buf
+    }
+
+    /// Spawn a background thread that calls [`refresh()`](Self::refresh) every
+    /// `interval`. The thread stops when the returned [`Ticker`] is dropped.
+    ///
+    /// An interval of one second or less keeps [`last()`](Self::last) at most
+    /// about one second behind real time.
+    pub fn spawn_ticker(&'static self, interval: Duration) -> Ticker {
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+        let handle = thread::Builder::new()
+            .name("kiters-timestamp-ticker".into())
+            .spawn(move || {
+                while !thread_stop.load(Ordering::Acquire) {
+                    self.refresh();
+                    thread::park_timeout(interval);
+                }
+            })
+            .expect("failed to spawn timestamp ticker thread");
+        Ticker {
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    /// Seqlock read. Returns `None` if a write is in progress or raced us.
+    #[inline]
+    fn load(&self) -> Option<(i64, [u8; 20])> {
+        let s1 = self.seq.load(Ordering::Acquire);
+        if s1 & 1 == 1 {
+            return None;
+        }
+        let second = self.second.load(Ordering::Relaxed);
+        let words = [
+            self.words[0].load(Ordering::Relaxed),
+            self.words[1].load(Ordering::Relaxed),
+            self.words[2].load(Ordering::Relaxed),
+        ];
+        fence(Ordering::Acquire);
+        if self.seq.load(Ordering::Relaxed) != s1 {
+            return None;
+        }
+        Some((second, unpack(words)))
+    }
+
+    /// Seqlock write. If another writer holds the lock, the update is skipped —
+    /// that writer is publishing an equally fresh value.
+    #[inline]
+    fn store(&self, second: i64, buf: &[u8; 20]) {
+        let s = self.seq.load(Ordering::Relaxed);
+        if s & 1 == 1
+            || self
+                .seq
+                .compare_exchange(s, s + 1, Ordering::Acquire, Ordering::Relaxed)
+                .is_err()
+        {
+            return;
+        }
+        fence(Ordering::Release);
+        let words = pack(buf);
+        self.second.store(second, Ordering::Relaxed);
+        for (slot, word) in self.words.iter().zip(words) {
+            slot.store(word, Ordering::Relaxed);
+        }
+        self.seq.store(s + 2, Ordering::Release);
+    }
+}
+
+impl Default for CachedTimestamp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[inline]
+fn pack(buf: &[u8; 20]) -> [u64; 3] {
+    let mut padded = [0u8; 24];
+    padded[..20].copy_from_slice(buf);
+    let mut words = [0u64; 3];
+    for (i, word) in words.iter_mut().enumerate() {
+        *word = u64::from_le_bytes(padded[i * 8..i * 8 + 8].try_into().unwrap());
+    }
+    words
+}
+
+#[inline]
+fn unpack(words: [u64; 3]) -> [u8; 20] {
+    let mut padded = [0u8; 24];
+    for (i, word) in words.iter().enumerate() {
+        padded[i * 8..i * 8 + 8].copy_from_slice(&word.to_le_bytes());
+    }
+    let mut buf = [0u8; 20];
+    buf.copy_from_slice(&padded[..20]);
+    buf
+}
+
+/// Handle to a background refresh thread started by
+/// [`CachedTimestamp::spawn_ticker`]. Stops and joins the thread on drop.
+pub struct Ticker {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Drop for Ticker {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timestamp::parse_utc_timestamp;
+    use time::OffsetDateTime;
+
+    fn parse(buf: &[u8; 20]) -> OffsetDateTime {
+        parse_utc_timestamp(std::str::from_utf8(buf).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_now_is_current() {
+        let cache = CachedTimestamp::new();
+        let before = OffsetDateTime::now_utc().unix_timestamp();
+        let ts = parse(&cache.now()).unix_timestamp();
+        let after = OffsetDateTime::now_utc().unix_timestamp();
+        assert!(before <= ts && ts <= after);
+    }
+
+    #[test]
+    fn test_now_with_clock_reformats_on_second_change() {
+        use crate::clock::MockClock;
+        let clock = MockClock::new(time::macros::datetime!(2023-10-27 10:00:00 UTC));
+        let cache = CachedTimestamp::new();
+        assert_eq!(&cache.now_with_clock(&clock), b"2023-10-27T10:00:00Z");
+        clock.advance(time::Duration::milliseconds(999));
+        assert_eq!(&cache.now_with_clock(&clock), b"2023-10-27T10:00:00Z");
+        clock.advance(time::Duration::milliseconds(1));
+        assert_eq!(&cache.now_with_clock(&clock), b"2023-10-27T10:00:01Z");
+        assert_eq!(&cache.last(), b"2023-10-27T10:00:01Z");
+    }
+
+    #[test]
+    fn test_pack_unpack_roundtrip() {
+        let buf = *b"2023-10-27T10:00:00Z";
+        assert_eq!(unpack(pack(&buf)), buf);
+    }
+
+    #[test]
+    fn test_store_load_roundtrip() {
+        let cache = CachedTimestamp::new();
+        assert_eq!(cache.load(), Some((UNSET, [0; 20])));
+        cache.store(1_698_400_800, b"2023-10-27T10:00:00Z");
+        assert_eq!(
+            cache.load(),
+            Some((1_698_400_800, *b"2023-10-27T10:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn test_last_initializes_empty_cache() {
+        let cache = CachedTimestamp::new();
+        let ts = cache.last();
+        parse(&ts);
+        assert_eq!(cache.last(), ts);
+    }
+
+    #[test]
+    fn test_last_does_not_read_clock() {
+        let cache = CachedTimestamp::new();
+        cache.store(0, b"1970-01-01T00:00:00Z");
+        assert_eq!(&cache.last(), b"1970-01-01T00:00:00Z");
+    }
+
+    #[test]
+    fn test_concurrent_access_never_torn() {
+        let cache = Arc::new(CachedTimestamp::new());
+        let handles: Vec<_> = (0..4)
+            .map(|t| {
+                let cache = Arc::clone(&cache);
+                thread::spawn(move || {
+                    for i in 0..10_000i64 {
+                        if t % 2 == 0 {
+                            let dt = OffsetDateTime::from_unix_timestamp(i * 86_400).unwrap();
+                            cache.store(i * 86_400, &format_utc_into(&dt));
+                        } else if let Some((second, buf)) = cache.load()
+                            && second != UNSET
+                        {
+                            assert_eq!(parse(&buf).unix_timestamp(), second);
+                        }
+                    }
+                })
+            })
+            .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_ticker_refreshes_and_stops() {
+        let cache: &'static CachedTimestamp = Box::leak(Box::new(CachedTimestamp::new()));
+        cache.store(0, b"1970-01-01T00:00:00Z");
+        let ticker = cache.spawn_ticker(Duration::from_millis(5));
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while &cache.last() == b"1970-01-01T00:00:00Z" {
+            assert!(
+                std::time::Instant::now() < deadline,
+                "ticker never refreshed"
+            );
+            thread::sleep(Duration::from_millis(1));
+        }
+        drop(ticker);
+        cache.store(0, b"1970-01-01T00:00:00Z");
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(&cache.last(), b"1970-01-01T00:00:00Z");
+    }
+}
@@ -0,0 +1,837 @@
+//! ISO 8601 durations (`P1DT2H`, `PT30S`) and time intervals
+//! (`2026-10-01T00:00:00Z/2026-10-02T00:00:00Z`, `start/P1D`, `P1D/end`).
+
+use super::{FromStrVisitor, TimestampError, UtcTimestamp, parse_digits, parse_utc_timestamp};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+use time::{Date, Duration, Month, OffsetDateTime};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+// ---------------------------------------------------------------------------
+// IsoDuration
+// ---------------------------------------------------------------------------
+
+/// An ISO 8601 duration: `PnYnMnWnDTnHnMnS`.
+///
+/// Years and months are *nominal* — their length depends on where they are
+/// applied — so they are kept separate from the exact components. Days and
+/// weeks are treated as exactly 24 hours and 7 days, which always holds in UTC.
+/// Only seconds may carry a fraction (`.` or `,` as decimal sign, up to nine
+/// digits). Negative durations are not supported.
+///
+/// Displays in canonical form: components in order, zero components omitted,
+/// and `PT0S` for the zero duration.
+///
+/// # Examples
+///
+/// ```
+/// use kiters::timestamp::IsoDuration;
+///
+/// let sla: IsoDuration = "P1DT2H".parse().unwrap();
+/// assert_eq!(sla.to_duration().unwrap(), time::Duration::hours(26));
+/// assert_eq!(sla.to_string(), "P1DT2H");
+///
+/// let retention: IsoDuration = "P3M".parse().unwrap();
+/// assert!(retention.is_nominal());
+/// assert!(retention.to_duration().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct IsoDuration {
+    years: u32,
+    months: u32,
+    weeks: u32,
+    days: u32,
+    hours: u32,
+    minutes: u32,
+    seconds: u32,
+    nanoseconds: u32,
+}
+
+impl IsoDuration {
+    /// The zero duration, `PT0S`.
+    pub const ZERO: Self = Self {
+        years: 0,
+        months: 0,
+        weeks: 0,
+        days: 0,
+        hours: 0,
+        minutes: 0,
+        seconds: 0,
+        nanoseconds: 0,
+    };
+
+    /// Set the years component.
+    pub const fn with_years(mut self, years: u32) -> Self {
+        self.years = years;
+        self
+    }
+
+    /// Set the months component.
+    pub const fn with_months(mut self, months: u32) -> Self {
+        self.months = months;
+        self
+    }
+
+    /// Set the weeks component.
+    pub const fn with_weeks(mut self, weeks: u32) -> Self {
+        self.weeks = weeks;
+        self
+    }
+
+    /// Set the days component.
+    pub const fn with_days(mut self, days: u32) -> Self {
+        self.days = days;
+        self
+    }
+
+    /// Set the hours component.
+    pub const fn with_hours(mut self, hours: u32) -> Self {
+        self.hours = hours;
+        self
+    }
+
+    /// Set the minutes component.
+    pub const fn with_minutes(mut self, minutes: u32) -> Self {
+        self.minutes = minutes;
+        self
+    }
+
+    /// Set the whole seconds component.
+    pub const fn with_seconds(mut self, seconds: u32) -> Self {
+        self.seconds = seconds;
+        self
+    }
+
+    /// Set the fractional seconds, in nanoseconds.
+    ///
+    /// Values above `999_999_999` are clamped to `999_999_999`; they are not
+    /// carried into the seconds component. Use
+    /// [`with_seconds`](Self::with_seconds) for whole seconds.
+    pub const fn with_nanoseconds(mut self, nanoseconds: u32) -> Self {
+        self.nanoseconds = if nanoseconds > 999_999_999 {
+            999_999_999
+        } else {
+            nanoseconds
+        };
+        self
+    }
+
+    /// Years component.
+    pub const fn years(&self) -> u32 {
+        self.years
+    }
+
+    /// Months component.
+    pub const fn months(&self) -> u32 {
+        self.months
+    }
+
+    /// Weeks component.
+    pub const fn weeks(&self) -> u32 {
+        self.weeks
+    }
+
+    /// Days component.
+    pub const fn days(&self) -> u32 {
+        self.days
+    }
+
+    /// Hours component.
+    pub const fn hours(&self) -> u32 {
+        self.hours
+    }
+
+    /// Minutes component.
+    pub const fn minutes(&self) -> u32 {
+        self.minutes
+    }
+
+    /// Whole seconds component.
+    pub const fn seconds(&self) -> u32 {
+        self.seconds
+    }
+
+    /// Fractional seconds, in nanoseconds.
+    pub const fn nanoseconds(&self) -> u32 {
+        self.nanoseconds
+    }
+
+    /// Whether every component is zero.
+    pub fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+
+    /// Whether the duration has year or month components, whose exact length
+    /// depends on the date they are applied to.
+    pub const fn is_nominal(&self) -> bool {
+        self.years != 0 || self.months != 0
+    }
+
+    /// The exact (weeks, days, hours, minutes, seconds) part as a `time::Duration`.
+    fn exact_part(&self) -> Duration {
+        let seconds = (self.weeks as i64 * 7 + self.days as i64) * SECONDS_PER_DAY
+            + self.hours as i64 * 3600
+            + self.minutes as i64 * 60
+            + self.seconds as i64;
+        Duration::new(seconds, self.nanoseconds as i32)
+    }
+
+    /// Convert to an exact `time::Duration`.
+    ///
+    /// Fails with [`TimestampError::NominalDuration`] if the duration has year
+    /// or month components; use [`add_to()`](Self::add_to) for those.
+    pub fn to_duration(&self) -> Result<Duration, TimestampError> {
+        if self.is_nominal() {
+            return Err(TimestampError::NominalDuration);
+        }
+        Ok(self.exact_part())
+    }
+
+    /// Add this duration to `dt` using calendar arithmetic.
+    ///
+    /// Years and months are applied first, clamping the day to the length of
+    /// the target month (`2026-01-31` + `P1M` = `2026-02-28`), then the exact
+    /// components.
+    pub fn add_to(&self, dt: OffsetDateTime) -> Result<OffsetDateTime, TimestampError> {
+        self.shift(dt, 1)
+    }
+
+    /// Subtract this duration from `dt`, mirroring [`add_to()`](Self::add_to).
+    pub fn sub_from(&self, dt: OffsetDateTime) -> Result<OffsetDateTime, TimestampError> {
+        self.shift(dt, -1)
+    }
+
+    /// Apply the duration `factor` times, computed from `dt` in one step so
+    /// that month clamping does not accumulate.
+    fn shift(&self, dt: OffsetDateTime, factor: i64) -> Result<OffsetDateTime, TimestampError> {
+        let total_months = (self.years as i64 * 12 + self.months as i64)
+            .checked_mul(factor)
+            .ok_or(TimestampError::OutOfRange)?;
+        let dt = if total_months != 0 {
+            let index = dt.year() as i64 * 12 + dt.month() as i64 - 1 + total_months;
+            let year =
+                i32::try_from(index.div_euclid(12)).map_err(|_| TimestampError::OutOfRange)?;
+            let month = Month::try_from(index.rem_euclid(12) as u8 + 1).unwrap();
+            let day = dt.day().min(time::util::days_in_month(month, year));
+            let date = Date::from_calendar_date(year, month, day)
+                .map_err(|_| TimestampError::OutOfRange)?;
+            dt.replace_date(date)
+        } else {
+            dt
+        };
+        let exact = self
+            .exact_part()
+            .checked_mul(i32::try_from(factor).map_err(|_| TimestampError::OutOfRange)?)
+            .ok_or(TimestampError::OutOfRange)?;
+        dt.checked_add(exact).ok_or(TimestampError::OutOfRange)
+    }
+}
+
+impl TryFrom<Duration> for IsoDuration {
+    type Error = TimestampError;
+
+    /// Express an exact, non-negative duration as days, hours, minutes and
+    /// seconds (e.g. 26 hours becomes `P1DT2H`).
+    fn try_from(d: Duration) -> Result<Self, Self::Error> {
+        if d.is_negative() {
+            return Err(TimestampError::InvalidDuration);
+        }
+        let total = d.whole_seconds();
+        let days =
+            u32::try_from(total / SECONDS_PER_DAY).map_err(|_| TimestampError::OutOfRange)?;
+        let rem = total % SECONDS_PER_DAY;
+        Ok(Self::ZERO
+            .with_days(days)
+            .with_hours((rem / 3600) as u32)
+            .with_minutes((rem % 3600 / 60) as u32)
+            .with_seconds((rem % 60) as u32)
+            .with_nanoseconds(d.subsec_nanoseconds() as u32))
+    }
+}
+
+impl fmt::Display for IsoDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("PT0S");
+        }
+        f.write_str("P")?;
+        for (value, designator) in [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ] {
+            if value != 0 {
+                write!(f, "{value}{designator}")?;
+            }
+        }
+        if self.hours != 0 || self.minutes != 0 || self.seconds != 0 || self.nanoseconds != 0 {
+            f.write_str("T")?;
+            if self.hours != 0 {
+                write!(f, "{}H", self.hours)?;
+            }
+            if self.minutes != 0 {
+                write!(f, "{}M", self.minutes)?;
+            }
+            if self.seconds != 0 || self.nanoseconds != 0 {
+                write!(f, "{}", self.seconds)?;
+                if self.nanoseconds != 0 {
+                    let fraction = format!("{:09}", self.nanoseconds);
+                    write!(f, ".{}", fraction.trim_end_matches('0'))?;
+                }
+                f.write_str("S")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for IsoDuration {
+    type Err = TimestampError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rest = s.strip_prefix('P').ok_or(TimestampError::InvalidDuration)?;
+        let (date_part, time_part) = match rest.split_once('T') {
+            Some((_, "")) => return Err(TimestampError::InvalidDuration),
+            Some((d, t)) => (d, Some(t)),
+            None => (rest, None),
+        };
+        if date_part.is_empty() && time_part.is_none() {
+            return Err(TimestampError::InvalidDuration);
+        }
+
+        let mut out = Self::ZERO;
+        let mut components = Components::new(date_part.as_bytes());
+        let mut allowed = "YMWD";
+        while let Some((value, fraction, designator)) = components.next()? {
+            let pos = allowed
+                .find(designator as char)
+                .ok_or(TimestampError::InvalidDuration)?;
+            allowed = &allowed[pos + 1..];
+            if fraction.is_some() {
+                return Err(TimestampError::InvalidDuration);
+            }
+            match designator {
+                b'Y' => out.years = value,
+                b'M' => out.months = value,
+                b'W' => out.weeks = value,
+                _ => out.days = value,
+            }
+        }
+
+        if let Some(time_part) = time_part {
+            let mut components = Components::new(time_part.as_bytes());
+            let mut allowed = "HMS";
+            while let Some((value, fraction, designator)) = components.next()? {
+                let pos = allowed
+                    .find(designator as char)
+                    .ok_or(TimestampError::InvalidDuration)?;
+                allowed = &allowed[pos + 1..];
+                if fraction.is_some() && designator != b'S' {
+                    return Err(TimestampError::InvalidDuration);
+                }
+                match designator {
+                    b'H' => out.hours = value,
+                    b'M' => out.minutes = value,
+                    _ => {
+                        out.seconds = value;
+                        out.nanoseconds = fraction.unwrap_or(0);
+                    }
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// Tokenizer for `<digits>[(.|,)<digits>]<designator>` runs.
+struct Components<'a> {
+    b: &'a [u8],
+    i: usize,
+}
+
+impl<'a> Components<'a> {
+    fn new(b: &'a [u8]) -> Self {
+        Self { b, i: 0 }
+    }
+
+    /// Next `(value, fraction_nanos, designator)`, or `None` at end of input.
+    fn next(&mut self) -> Result<Option<(u32, Option<u32>, u8)>, TimestampError> {
+        if self.i == self.b.len() {
+            return Ok(None);
+        }
+        let digits = self.take_digits();
+        if digits.is_empty() {
+            return Err(TimestampError::InvalidDuration);
+        }
+        // Any u32 component, so every displayed duration parses back.
+        let value = digits
+            .iter()
+            .try_fold(0u32, |n, &d| {
+                n.checked_mul(10)?.checked_add((d - b'0') as u32)
+            })
+            .ok_or(TimestampError::InvalidDuration)?;
+        let fraction = match self.b.get(self.i) {
+            Some(b'.' | b',') => {
+                self.i += 1;
+                let digits = self.take_digits();
+                if digits.is_empty() || digits.len() > 9 {
+                    return Err(TimestampError::InvalidDuration);
+                }
+                let n = parse_digits(digits).ok_or(TimestampError::InvalidDuration)?;
+                Some(n * 10u32.pow(9 - digits.len() as u32))
+            }
+            _ => None,
+        };
+        let designator = *self.b.get(self.i).ok_or(TimestampError::InvalidDuration)?;
+        self.i += 1;
+        Ok(Some((value, fraction, designator)))
+    }
+
+    fn take_digits(&mut self) -> &'a [u8] {
+        let start = self.i;
+        while self.i < self.b.len() && self.b[self.i].is_ascii_digit() {
+            self.i += 1;
+        }
+        &self.b[start..self.i]
+    }
+}
+
+impl Serialize for IsoDuration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IsoDuration {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("an ISO 8601 duration"))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// UtcInterval
+// ---------------------------------------------------------------------------
+
+/// A half-open UTC time interval `[start, end)` with second-precision endpoints.
+///
+/// Parses the three ISO 8601 forms with a canonical UTC endpoint:
+/// `start/end`, `start/duration` and `duration/end`. Always displays as
+/// `start/end` using the canonical format.
+///
+/// # Examples
+///
+/// ```
+/// use kiters::timestamp::{IsoDuration, UtcInterval};
+///
+/// let day: UtcInterval = "2026-10-01T00:00:00Z/P1D".parse().unwrap();
+/// assert_eq!(day.to_string(), "2026-10-01T00:00:00Z/2026-10-02T00:00:00Z");
+///
+/// let noon = "2026-10-01T12:00:00Z".parse().unwrap();
+/// assert!(day.contains(noon));
+///
+/// let hours: Vec<_> = day
+///     .steps(&"PT6H".parse().unwrap())
+///     .unwrap()
+///     .map(|t| t.to_string())
+///     .collect();
+/// assert_eq!(hours.len(), 4);
+/// assert_eq!(hours[3], "2026-10-01T18:00:00Z");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UtcInterval {
+    start: UtcTimestamp,
+    end: UtcTimestamp,
+}
+
+impl UtcInterval {
+    /// Create an interval. Fails with [`TimestampError::InvalidInterval`] if
+    /// `end` is before `start`.
+    pub fn new(start: UtcTimestamp, end: UtcTimestamp) -> Result<Self, TimestampError> {
+        if end < start {
+            return Err(TimestampError::InvalidInterval);
+        }
+        Ok(Self { start, end })
+    }
+
+    /// Create the interval `start/duration`.
+    pub fn from_start(start: UtcTimestamp, duration: &IsoDuration) -> Result<Self, TimestampError> {
+        let end = UtcTimestamp::new(duration.add_to(start.into())?)?;
+        Self::new(start, end)
+    }
+
+    /// Create the interval `duration/end`.
+    pub fn from_end(duration: &IsoDuration, end: UtcTimestamp) -> Result<Self, TimestampError> {
+        let start = UtcTimestamp::new(duration.sub_from(end.into())?)?;
+        Self::new(start, end)
+    }
+
+    /// Inclusive start.
+    pub fn start(&self) -> UtcTimestamp {
+        self.start
+    }
+
+    /// Exclusive end.
+    pub fn end(&self) -> UtcTimestamp {
+        self.end
+    }
+
+    /// Exact length of the interval.
+    pub fn duration(&self) -> Duration {
+        self.end.as_offset_date_time() - self.start.as_offset_date_time()
+    }
+
+    /// Whether the interval is empty (`start == end`).
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Whether `t` lies in `[start, end)`.
+    pub fn contains(&self, t: UtcTimestamp) -> bool {
+        self.start <= t && t < self.end
+    }
+
+    /// Whether `dt` lies in `[start, end)`, at full precision.
+    pub fn contains_datetime(&self, dt: OffsetDateTime) -> bool {
+        self.start.as_offset_date_time() <= dt && dt < self.end.as_offset_date_time()
+    }
+
+    /// Whether `other` lies entirely within this interval.
+    pub fn contains_interval(&self, other: &UtcInterval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether the two intervals share any instant.
+    pub fn overlaps(&self, other: &UtcInterval) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Iterate over `start`, `start + step`, `start + 2*step`, ... while
+    /// before `end`.
+    ///
+    /// Each item is computed directly from `start`, so month steps do not
+    /// drift (`P1M` from `01-31` yields `02-28`, `03-31`, ...). The step must
+    /// be non-zero and a whole number of seconds.
+    pub fn steps(&self, step: &IsoDuration) -> Result<Steps, TimestampError> {
+        if step.is_zero() || step.nanoseconds != 0 {
+            return Err(TimestampError::InvalidDuration);
+        }
+        Ok(Steps {
+            start: self.start.as_offset_date_time(),
+            end: self.end.as_offset_date_time(),
+            step: *step,
+            index: 0,
+        })
+    }
+}
+
+impl fmt::Display for UtcInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.start, self.end)
+    }
+}
+
+impl FromStr for UtcInterval {
+    type Err = TimestampError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (left, right) = s.split_once('/').ok_or(TimestampError::InvalidInterval)?;
+        match (left.starts_with('P'), right.starts_with('P')) {
+            (false, false) => Self::new(
+                UtcTimestamp(parse_utc_timestamp(left)?),
+                UtcTimestamp(parse_utc_timestamp(right)?),
+            ),
+            (false, true) => {
+                Self::from_start(UtcTimestamp(parse_utc_timestamp(left)?), &right.parse()?)
+            }
+            (true, false) => {
+                Self::from_end(&left.parse()?, UtcTimestamp(parse_utc_timestamp(right)?))
+            }
+            (true, true) => Err(TimestampError::InvalidInterval),
+        }
+    }
+}
+
+impl Serialize for UtcInterval {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UtcInterval {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("an ISO 8601 UTC interval"))
+    }
+}
+
+/// Iterator over step points in an interval, returned by [`UtcInterval::steps`].
+#[derive(Debug, Clone)]
+pub struct Steps {
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+    step: IsoDuration,
+    index: i64,
+}
+
+impl Iterator for Steps {
+    type Item = UtcTimestamp;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let t = self.step.shift(self.start, self.index).ok()?;
+        if t >= self.end {
+            return None;
+        }
+        self.index += 1;
+        // Between two in-range endpoints, so always representable.
+        UtcTimestamp::new(t).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    fn ts(s: &str) -> UtcTimestamp {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display_durations() {
+        for s in [
+            "P1Y2M3W4DT5H6M7S",
+            "P1DT2H",
+            "PT30S",
+            "P3M",
+            "PT0.5S",
+            "PT1M",
+            "P2W",
+        ] {
+            assert_eq!(s.parse::<IsoDuration>().unwrap().to_string(), s);
+        }
+        assert_eq!("PT0S".parse::<IsoDuration>().unwrap(), IsoDuration::ZERO);
+        assert_eq!(IsoDuration::ZERO.to_string(), "PT0S");
+        assert_eq!(
+            "PT1,250S".parse::<IsoDuration>().unwrap().to_string(),
+            "PT1.25S"
+        );
+        assert_eq!("P0D".parse::<IsoDuration>().unwrap().to_string(), "PT0S");
+    }
+
+    #[test]
+    fn test_u32_max_components_roundtrip() {
+        let d = IsoDuration::default().with_days(u32::MAX);
+        assert_eq!(d.to_string(), "P4294967295D");
+        assert_eq!("P4294967295D".parse::<IsoDuration>(), Ok(d));
+        let json = serde_json::to_string(&d).unwrap();
+        assert_eq!(serde_json::from_str::<IsoDuration>(&json).unwrap(), d);
+        assert_eq!(
+            "P4294967296D".parse::<IsoDuration>(),
+            Err(TimestampError::InvalidDuration)
+        );
+    }
+
+    #[test]
+    fn test_parse_duration_errors() {
+        for s in [
+            "",
+            "P",
+            "PT",
+            "1D",
+            "P1",
+            "PD",
+            "P1H",
+            "PT1D",
+            "P1D1Y",
+            "P1M1M",
+            "P1.5D",
+            "PT1.5M",
+            "PT1.S",
+            "PT1.0000000001S",
+            "P1DT",
+            "p1d",
+            "P-1D",
+            "P9999999999D",
+        ] {
+            assert_eq!(
+                s.parse::<IsoDuration>(),
+                Err(TimestampError::InvalidDuration),
+                "{s:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_duration() {
+        let d: IsoDuration = "P1W1DT1H1M1.5S".parse().unwrap();
+        assert_eq!(
+            d.to_duration().unwrap(),
+            Duration::days(8)
+                + Duration::hours(1)
+                + Duration::minutes(1)
+                + Duration::milliseconds(1500)
+        );
+        assert_eq!(
+            "P1Y".parse::<IsoDuration>().unwrap().to_duration(),
+            Err(TimestampError::NominalDuration)
+        );
+    }
+
+    #[test]
+    fn test_try_from_duration() {
+        let d = IsoDuration::try_from(Duration::hours(26) + Duration::milliseconds(5)).unwrap();
+        assert_eq!(d.to_string(), "P1DT2H0.005S");
+        assert_eq!(
+            IsoDuration::try_from(Duration::seconds(-1)),
+            Err(TimestampError::InvalidDuration)
+        );
+    }
+
+    #[test]
+    fn test_add_months_clamps_day() {
+        let d: IsoDuration = "P1M".parse().unwrap();
+        assert_eq!(
+            d.add_to(datetime!(2026-01-31 10:00 UTC)).unwrap(),
+            datetime!(2026-02-28 10:00 UTC)
+        );
+        assert_eq!(
+            d.sub_from(datetime!(2026-03-31 10:00 UTC)).unwrap(),
+            datetime!(2026-02-28 10:00 UTC)
+        );
+        let d: IsoDuration = "P1Y".parse().unwrap();
+        assert_eq!(
+            d.add_to(datetime!(2024-02-29 00:00 UTC)).unwrap(),
+            datetime!(2025-02-28 00:00 UTC)
+        );
+    }
+
+    #[test]
+    fn test_interval_forms() {
+        let a: UtcInterval = "2026-10-01T00:00:00Z/2026-10-02T00:00:00Z".parse().unwrap();
+        let b: UtcInterval = "2026-10-01T00:00:00Z/P1D".parse().unwrap();
+        let c: UtcInterval = "P1D/2026-10-02T00:00:00Z".parse().unwrap();
+        assert_eq!(a, b);
+        assert_eq!(b, c);
+        assert_eq!(a.duration(), Duration::DAY);
+        assert_eq!(a.to_string(), "2026-10-01T00:00:00Z/2026-10-02T00:00:00Z");
+    }
+
+    #[test]
+    fn test_interval_errors() {
+        assert_eq!(
+            "2026-10-02T00:00:00Z/2026-10-01T00:00:00Z".parse::<UtcInterval>(),
+            Err(TimestampError::InvalidInterval)
+        );
+        assert_eq!(
+            "2026-10-01T00:00:00Z".parse::<UtcInterval>(),
+            Err(TimestampError::InvalidInterval)
+        );
+        assert_eq!(
+            "P1D/P2D".parse::<UtcInterval>(),
+            Err(TimestampError::InvalidInterval)
+        );
+        assert_eq!(
+            "2026-10-01T00:00:00Z/P1X".parse::<UtcInterval>(),
+            Err(TimestampError::InvalidDuration)
+        );
+        assert_eq!(
+            "2026-13-01T00:00:00Z/P1D".parse::<UtcInterval>(),
+            Err(TimestampError::InvalidMonth)
+        );
+    }
+
+    #[test]
+    fn test_interval_containment() {
+        let day: UtcInterval = "2026-10-01T00:00:00Z/P1D".parse().unwrap();
+        assert!(day.contains(ts("2026-10-01T00:00:00Z")));
+        assert!(day.contains(ts("2026-10-01T23:59:59Z")));
+        assert!(!day.contains(ts("2026-10-02T00:00:00Z")));
+        assert!(day.contains_datetime(datetime!(2026-10-01 23:59:59.999 UTC)));
+
+        let morning: UtcInterval = "2026-10-01T06:00:00Z/PT6H".parse().unwrap();
+        let next: UtcInterval = "2026-10-02T00:00:00Z/P1D".parse().unwrap();
+        assert!(day.contains_interval(&morning));
+        assert!(!morning.contains_interval(&day));
+        assert!(day.overlaps(&morning));
+        assert!(!day.overlaps(&next));
+    }
+
+    #[test]
+    fn test_interval_steps_do_not_drift() {
+        let year: UtcInterval = "2026-01-31T00:00:00Z/2026-05-01T00:00:00Z".parse().unwrap();
+        let months: Vec<_> = year
+            .steps(&"P1M".parse().unwrap())
+            .unwrap()
+            .map(|t| t.to_string())
+            .collect();
+        assert_eq!(
+            months,
+            [
+                "2026-01-31T00:00:00Z",
+                "2026-02-28T00:00:00Z",
+                "2026-03-31T00:00:00Z",
+                "2026-04-30T00:00:00Z",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_interval_steps_reject_bad_step() {
+        let day: UtcInterval = "2026-10-01T00:00:00Z/P1D".parse().unwrap();
+        assert!(day.steps(&IsoDuration::ZERO).is_err());
+        assert!(day.steps(&"PT0.5S".parse().unwrap()).is_err());
+        let empty =
+            UtcInterval::new(ts("2026-10-01T00:00:00Z"), ts("2026-10-01T00:00:00Z")).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.steps(&"PT1S".parse().unwrap()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_serde_as_strings() {
+        let d: IsoDuration = "P1DT2H".parse().unwrap();
+        assert_eq!(serde_json::to_string(&d).unwrap(), r#""P1DT2H""#);
+        let i: UtcInterval = "2026-10-01T00:00:00Z/P1D".parse().unwrap();
+        let json = serde_json::to_string(&i).unwrap();
+        assert_eq!(json, r#""2026-10-01T00:00:00Z/2026-10-02T00:00:00Z""#);
+        assert_eq!(serde_json::from_str::<UtcInterval>(&json).unwrap(), i);
+        assert_eq!(
+            serde_json::from_str::<IsoDuration>(r#""P1DT2H""#).unwrap(),
+            d
+        );
+        assert!(serde_json::from_str::<IsoDuration>(r#""1DT2H""#).is_err());
+        assert!(serde_json::from_str::<UtcInterval>("42").is_err());
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn duration_display_parse_roundtrip(
+            parts in any::<[u32; 7]>(),
+            nanos in 0u32..1_000_000_000,
+        ) {
+            let d = IsoDuration::ZERO
+                .with_years(parts[0])
+                .with_months(parts[1])
+                .with_weeks(parts[2])
+                .with_days(parts[3])
+                .with_hours(parts[4])
+                .with_minutes(parts[5])
+                .with_seconds(parts[6])
+                .with_nanoseconds(nanos);
+            let parsed: IsoDuration = d.to_string().parse().unwrap();
+            prop_assert_eq!(parsed, d);
+        }
+
+        #[test]
+        fn duration_parse_never_panics(s in "P[0-9YMWDTHS.,]{0,20}") {
+            let _ = s.parse::<IsoDuration>();
+        }
+    }
+}
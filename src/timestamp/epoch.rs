@@ -0,0 +1,361 @@
+//! Numeric Unix epochs in seconds, milliseconds, microseconds or
+//! nanoseconds, with the unit inferred from magnitude when not given.
+
+use super::{TimestampError, format_utc_into, parse_utc_timestamp};
+use time::OffsetDateTime;
+
+/// Earliest instant in the canonical range, `0000-01-01T00:00:00Z`, in nanoseconds.
+const MIN_NANOS: i128 = -62_167_219_200 * 1_000_000_000;
+/// Latest instant in the canonical range, `9999-12-31T23:59:59.999999999Z`, in nanoseconds.
+const MAX_NANOS: i128 = 253_402_300_800 * 1_000_000_000 - 1;
+
+/// Unit of a numeric Unix epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EpochUnit {
+    /// Seconds since the epoch.
+    Seconds,
+    /// Milliseconds since the epoch.
+    Millis,
+    /// Microseconds since the epoch.
+    Micros,
+    /// Nanoseconds since the epoch.
+    Nanos,
+}
+
+impl EpochUnit {
+    /// Infer the unit of `value` from its magnitude.
+    ///
+    /// | Absolute value    | Unit      | Covers                       |
+    /// |-------------------|-----------|------------------------------|
+    /// | `< 10^11`         | seconds   | up to year 5138              |
+    /// | `< 10^14`         | millis    | 1973 to year 5138            |
+    /// | `< 10^17`         | micros    | 1973 to year 5138            |
+    /// | otherwise         | nanos     | 1973 onwards                 |
+    ///
+    /// Instants between 1970 and early 1973 are ambiguous at sub-second
+    /// units; pass an explicit unit for those.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kiters::timestamp::EpochUnit;
+    ///
+    /// assert_eq!(EpochUnit::detect(1_698_400_800), EpochUnit::Seconds);
+    /// assert_eq!(EpochUnit::detect(1_698_400_800_000), EpochUnit::Millis);
+    /// assert_eq!(EpochUnit::detect(1_698_400_800_000_000), EpochUnit::Micros);
+    /// assert_eq!(EpochUnit::detect(1_698_400_800_000_000_000), EpochUnit::Nanos);
+    /// ```
+    pub const fn detect(value: i128) -> Self {
+        match value.unsigned_abs() {
+            0..100_000_000_000 => Self::Seconds,
+            100_000_000_000..100_000_000_000_000 => Self::Millis,
+            100_000_000_000_000..100_000_000_000_000_000 => Self::Micros,
+            _ => Self::Nanos,
+        }
+    }
+
+    const fn nanos_per_unit(self) -> i128 {
+        match self {
+            Self::Seconds => 1_000_000_000,
+            Self::Millis => 1_000_000,
+            Self::Micros => 1_000,
+            Self::Nanos => 1,
+        }
+    }
+
+    /// Number of fractional digits that fit below one unit.
+    const fn fraction_digits(self) -> usize {
+        match self {
+            Self::Seconds => 9,
+            Self::Millis => 6,
+            Self::Micros => 3,
+            Self::Nanos => 0,
+        }
+    }
+}
+
+fn from_nanos(nanos: i128) -> Result<OffsetDateTime, TimestampError> {
+    if !(MIN_NANOS..=MAX_NANOS).contains(&nanos) {
+        return Err(TimestampError::OutOfRange);
+    }
+    // Range-checked above.
+    Ok(OffsetDateTime::from_unix_timestamp_nanos(nanos).unwrap())
+}
+
+fn canonical(dt: &OffsetDateTime) -> String {
+    let buf = format_utc_into(dt);
+    // All bytes written are ASCII.
+    String::from_utf8(buf.to_vec()).unwrap()
+}
+
+/// Convert a numeric epoch to a UTC `OffsetDateTime`.
+///
+/// With `unit` set to `None` the unit is inferred by [`EpochUnit::detect`].
+/// Fails with [`TimestampError::OutOfRange`] outside years `0000..=9999`.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::{EpochUnit, parse_epoch};
+/// use time::macros::datetime;
+///
+/// let expected = datetime!(2023-10-27 10:00:00 UTC);
+/// assert_eq!(parse_epoch(1_698_400_800, None).unwrap(), expected);
+/// assert_eq!(parse_epoch(1_698_400_800_000, None).unwrap(), expected);
+/// // Force the unit for values too small to detect.
+/// assert_eq!(
+///     parse_epoch(5_000, Some(EpochUnit::Millis)).unwrap(),
+///     datetime!(1970-01-01 00:00:05 UTC)
+/// );
+/// ```
+pub fn parse_epoch(value: i128, unit: Option<EpochUnit>) -> Result<OffsetDateTime, TimestampError> {
+    let unit = unit.unwrap_or_else(|| EpochUnit::detect(value));
+    let nanos = value
+        .checked_mul(unit.nanos_per_unit())
+        .ok_or(TimestampError::OutOfRange)?;
+    from_nanos(nanos)
+}
+
+/// Convert a numeric epoch string to a UTC `OffsetDateTime`.
+///
+/// Accepts an optional leading `-`, digits, and an optional `.` followed by
+/// fractional digits of the unit (as in `1698400800.25`). The unit is
+/// inferred from the integer part unless `unit` is given; fractional digits
+/// below one nanosecond are truncated. Anything else fails with
+/// [`TimestampError::InvalidEpoch`].
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::parse_epoch_str;
+///
+/// let dt = parse_epoch_str("1698400800.25", None).unwrap();
+/// assert_eq!(dt.unix_timestamp(), 1_698_400_800);
+/// assert_eq!(dt.millisecond(), 250);
+/// ```
+pub fn parse_epoch_str(s: &str, unit: Option<EpochUnit>) -> Result<OffsetDateTime, TimestampError> {
+    let (negative, rest) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let (int, frac) = match rest.split_once('.') {
+        Some((int, frac)) => (int, Some(frac)),
+        None => (rest, None),
+    };
+    if int.is_empty()
+        || !int.bytes().all(|c| c.is_ascii_digit())
+        || frac.is_some_and(|f| f.is_empty() || !f.bytes().all(|c| c.is_ascii_digit()))
+    {
+        return Err(TimestampError::InvalidEpoch);
+    }
+
+    let mut whole: i128 = 0;
+    for c in int.bytes() {
+        whole = whole
+            .checked_mul(10)
+            .and_then(|n| n.checked_add((c - b'0') as i128))
+            .ok_or(TimestampError::OutOfRange)?;
+    }
+    let unit = unit.unwrap_or_else(|| EpochUnit::detect(whole));
+
+    let mut nanos = whole
+        .checked_mul(unit.nanos_per_unit())
+        .ok_or(TimestampError::OutOfRange)?;
+    if let Some(frac) = frac {
+        let width = unit.fraction_digits();
+        let mut sub: i128 = 0;
+        for i in 0..width {
+            let digit = frac.as_bytes().get(i).map_or(0, |c| c - b'0');
+            sub = sub * 10 + digit as i128;
+        }
+        nanos = nanos.checked_add(sub).ok_or(TimestampError::OutOfRange)?;
+    }
+    from_nanos(if negative { -nanos } else { nanos })
+}
+
+/// Convert a numeric epoch to the canonical `YYYY-MM-DDTHH:MM:SSZ` string,
+/// truncating sub-second precision.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::epoch_to_utc_timestamp;
+///
+/// assert_eq!(
+///     epoch_to_utc_timestamp(1_698_400_800_123_456, None).unwrap(),
+///     "2023-10-27T10:00:00Z"
+/// );
+/// ```
+pub fn epoch_to_utc_timestamp(
+    value: i128,
+    unit: Option<EpochUnit>,
+) -> Result<String, TimestampError> {
+    parse_epoch(value, unit).map(|dt| canonical(&dt))
+}
+
+/// Like [`epoch_to_utc_timestamp`], for epochs given as strings; see
+/// [`parse_epoch_str`] for the accepted syntax.
+pub fn epoch_str_to_utc_timestamp(
+    s: &str,
+    unit: Option<EpochUnit>,
+) -> Result<String, TimestampError> {
+    parse_epoch_str(s, unit).map(|dt| canonical(&dt))
+}
+
+/// Convert an `OffsetDateTime` to a numeric epoch in `unit`, rounding
+/// towards negative infinity.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::{EpochUnit, to_epoch};
+/// use time::macros::datetime;
+///
+/// let dt = datetime!(2023-10-27 10:00:00.5 UTC);
+/// assert_eq!(to_epoch(&dt, EpochUnit::Seconds), 1_698_400_800);
+/// assert_eq!(to_epoch(&dt, EpochUnit::Millis), 1_698_400_800_500);
+/// ```
+pub fn to_epoch(dt: &OffsetDateTime, unit: EpochUnit) -> i128 {
+    dt.unix_timestamp_nanos().div_euclid(unit.nanos_per_unit())
+}
+
+/// Convert a canonical `YYYY-MM-DDTHH:MM:SSZ` string to a numeric epoch in `unit`.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::{EpochUnit, utc_timestamp_to_epoch};
+///
+/// assert_eq!(
+///     utc_timestamp_to_epoch("2023-10-27T10:00:00Z", EpochUnit::Micros).unwrap(),
+///     1_698_400_800_000_000
+/// );
+/// ```
+pub fn utc_timestamp_to_epoch(s: &str, unit: EpochUnit) -> Result<i128, TimestampError> {
+    parse_utc_timestamp(s).map(|dt| to_epoch(&dt, unit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn test_detect_units() {
+        let dt = datetime!(2023-10-27 10:00:00 UTC);
+        for unit in [
+            EpochUnit::Seconds,
+            EpochUnit::Millis,
+            EpochUnit::Micros,
+            EpochUnit::Nanos,
+        ] {
+            let value = to_epoch(&dt, unit);
+            assert_eq!(EpochUnit::detect(value), unit);
+            assert_eq!(EpochUnit::detect(-value), unit);
+            assert_eq!(parse_epoch(value, None).unwrap(), dt);
+            assert_eq!(parse_epoch_str(&value.to_string(), None).unwrap(), dt);
+        }
+    }
+
+    #[test]
+    fn test_explicit_unit_overrides_detection() {
+        assert_eq!(
+            parse_epoch(1_698_400_800, Some(EpochUnit::Millis)).unwrap(),
+            datetime!(1970-01-20 15:46:40.8 UTC)
+        );
+        assert_eq!(
+            parse_epoch_str("0", Some(EpochUnit::Nanos)).unwrap(),
+            OffsetDateTime::UNIX_EPOCH
+        );
+    }
+
+    #[test]
+    fn test_negative_and_fractional() {
+        assert_eq!(
+            parse_epoch_str("-1.5", None).unwrap(),
+            datetime!(1969-12-31 23:59:58.5 UTC)
+        );
+        assert_eq!(
+            parse_epoch_str("1698400800123.4567", None).unwrap(),
+            datetime!(2023-10-27 10:00:00.123_456_7 UTC)
+        );
+        // Digits below one nanosecond are truncated.
+        assert_eq!(
+            parse_epoch_str("1.0000000019", Some(EpochUnit::Seconds)).unwrap(),
+            datetime!(1970-01-01 00:00:01.000_000_001 UTC)
+        );
+    }
+
+    #[test]
+    fn test_canonical_strings() {
+        assert_eq!(
+            epoch_str_to_utc_timestamp("1698400800999", None).unwrap(),
+            "2023-10-27T10:00:00Z"
+        );
+        assert_eq!(
+            utc_timestamp_to_epoch("1969-12-31T23:59:59Z", EpochUnit::Millis),
+            Ok(-1000)
+        );
+        assert_eq!(
+            to_epoch(&datetime!(1969-12-31 23:59:59.5 UTC), EpochUnit::Seconds),
+            -1
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        for bad in ["", "-", "abc", "12a", "1.", ".5", "+1", " 1", "1e9"] {
+            assert_eq!(
+                parse_epoch_str(bad, None),
+                Err(TimestampError::InvalidEpoch),
+                "{bad:?}"
+            );
+        }
+        assert_eq!(
+            parse_epoch(253_402_300_800, Some(EpochUnit::Seconds)),
+            Err(TimestampError::OutOfRange)
+        );
+        assert_eq!(
+            parse_epoch(i128::MAX, Some(EpochUnit::Seconds)),
+            Err(TimestampError::OutOfRange)
+        );
+        assert_eq!(
+            parse_epoch_str(&"9".repeat(60), None),
+            Err(TimestampError::OutOfRange)
+        );
+        assert_eq!(
+            parse_epoch_str(
+                "170141183460469231731687303715.999999999",
+                Some(EpochUnit::Seconds)
+            ),
+            Err(TimestampError::OutOfRange)
+        );
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn roundtrip_explicit_unit(nanos in MIN_NANOS..=MAX_NANOS) {
+            let dt = OffsetDateTime::from_unix_timestamp_nanos(nanos).unwrap();
+            for unit in [EpochUnit::Seconds, EpochUnit::Millis, EpochUnit::Micros, EpochUnit::Nanos] {
+                let value = to_epoch(&dt, unit);
+                let back = parse_epoch(value, Some(unit)).unwrap();
+                prop_assert!(back <= dt);
+                prop_assert!(dt - back < time::Duration::nanoseconds(unit.nanos_per_unit() as i64));
+            }
+        }
+
+        #[test]
+        fn parse_never_panics(s in "-?[0-9]{0,45}(\\.[0-9]{0,12})?") {
+            let _ = parse_epoch_str(&s, None);
+            for unit in [EpochUnit::Seconds, EpochUnit::Millis, EpochUnit::Micros, EpochUnit::Nanos] {
+                let _ = parse_epoch_str(&s, Some(unit));
+            }
+        }
+    }
+}
@@ -0,0 +1,508 @@
+//! Hybrid logical clock (HLC) timestamps for causal ordering across services.
+//!
+//! An HLC timestamp pairs physical time (milliseconds) with a logical counter,
+//! following Kulkarni et al., "Logical Physical Clocks" (2014). Local events
+//! call [`HybridLogicalClock::now`]; received messages are merged with
+//! [`HybridLogicalClock::update`], so every event is ordered after everything
+//! that causally precedes it while staying close to wall-clock time.
+
+use super::{
+    FromStrVisitor, TimestampError, expect_separator, format_utc_millis_into, parse_fraction,
+    parse_hms, parse_ymd,
+};
+use crate::clock::{Clock, SystemClock};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use time::{Duration, OffsetDateTime, PrimitiveDateTime};
+
+/// Length of the string encoding: `YYYY-MM-DDTHH:MM:SS.sssZ-llll-nnnn`.
+const HLC_STRING_LEN: usize = 34;
+
+/// Physical time is stored in the upper 48 bits of the packed state.
+const MAX_PHYSICAL_MS: u64 = (1 << 48) - 1;
+
+// ---------------------------------------------------------------------------
+// HlcTimestamp
+// ---------------------------------------------------------------------------
+
+/// A hybrid logical clock timestamp: physical milliseconds, logical counter,
+/// and the issuing node's ID as a final tie-breaker.
+///
+/// Ordered by `(physical, logical, node)`, which gives a deterministic total
+/// order over events from any number of nodes.
+///
+/// Encodes as `YYYY-MM-DDTHH:MM:SS.sssZ-llll-nnnn`: the canonical UTC format
+/// at millisecond precision, then the logical counter and node ID as four
+/// lowercase hex digits each. The encoding is fixed-width, so string order
+/// matches timestamp order. Serializes as this string.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::HlcTimestamp;
+///
+/// let ts: HlcTimestamp = "2026-10-16T09:00:00.123Z-0002-002a".parse().unwrap();
+/// assert_eq!(ts.logical(), 2);
+/// assert_eq!(ts.node(), 42);
+/// assert_eq!(ts.to_string(), "2026-10-16T09:00:00.123Z-0002-002a");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HlcTimestamp {
+    physical_ms: u64,
+    logical: u16,
+    node: u16,
+}
+
+impl HlcTimestamp {
+    /// Create a timestamp from its parts. Fails with
+    /// [`TimestampError::OutOfRange`] if `physical_ms` exceeds 48 bits or
+    /// falls after year 9999.
+    pub fn new(physical_ms: u64, logical: u16, node: u16) -> Result<Self, TimestampError> {
+        if physical_ms > 253_402_300_799_999 {
+            return Err(TimestampError::OutOfRange);
+        }
+        Ok(Self {
+            physical_ms,
+            logical,
+            node,
+        })
+    }
+
+    /// Physical component, in milliseconds since the Unix epoch.
+    pub const fn physical_ms(&self) -> u64 {
+        self.physical_ms
+    }
+
+    /// Logical counter.
+    pub const fn logical(&self) -> u16 {
+        self.logical
+    }
+
+    /// ID of the node that issued the timestamp.
+    pub const fn node(&self) -> u16 {
+        self.node
+    }
+
+    /// Physical component as a UTC `OffsetDateTime`.
+    pub fn datetime(&self) -> OffsetDateTime {
+        // Range-checked on construction.
+        OffsetDateTime::from_unix_timestamp_nanos(self.physical_ms as i128 * 1_000_000).unwrap()
+    }
+}
+
+impl fmt::Display for HlcTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let buf = format_utc_millis_into(&self.datetime());
+        // All bytes written are ASCII.
+        let ts = std::str::from_utf8(&buf).unwrap();
+        write!(f, "{ts}-{:04x}-{:04x}", self.logical, self.node)
+    }
+}
+
+impl FromStr for HlcTimestamp {
+    type Err = TimestampError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let b = s.as_bytes();
+        if b.len() != HLC_STRING_LEN {
+            return Err(TimestampError::InvalidLength(b.len()));
+        }
+        let date = parse_ymd(b, 0)?;
+        expect_separator(b, 10, b'T')?;
+        let time = parse_hms(b, 11)?;
+        expect_separator(b, 19, b'.')?;
+        let nanos = parse_fraction(&b[20..23])?;
+        expect_separator(b, 23, b'Z')?;
+        expect_separator(b, 24, b'-')?;
+        let logical = parse_hex4(&b[25..29]).ok_or(TimestampError::InvalidLogical)?;
+        expect_separator(b, 29, b'-')?;
+        let node = parse_hex4(&b[30..34]).ok_or(TimestampError::InvalidNode)?;
+
+        let dt = PrimitiveDateTime::new(date, time).assume_utc();
+        let physical_ms =
+            u64::try_from(dt.unix_timestamp_nanos() / 1_000_000 + (nanos / 1_000_000) as i128)
+                .map_err(|_| TimestampError::OutOfRange)?;
+        Self::new(physical_ms, logical, node)
+    }
+}
+
+/// Parse exactly four lowercase hex digits.
+fn parse_hex4(b: &[u8]) -> Option<u16> {
+    let mut n = 0u16;
+    for &c in b {
+        let digit = match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            _ => return None,
+        };
+        n = n << 4 | digit as u16;
+    }
+    Some(n)
+}
+
+impl Serialize for HlcTimestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for HlcTimestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("a hybrid logical clock timestamp"))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// HybridLogicalClock
+// ---------------------------------------------------------------------------
+
+/// Thread-safe hybrid logical clock for one node.
+///
+/// The `(physical, logical)` state is packed into a single `AtomicU64`
+/// (48 + 16 bits) and advanced with compare-and-swap, so `now()` and
+/// `update()` are lock-free.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::HybridLogicalClock;
+///
+/// let a = HybridLogicalClock::new(1);
+/// let b = HybridLogicalClock::new(2);
+///
+/// let sent = a.now().unwrap();
+/// let received = b.update(sent).unwrap();
+/// assert!(received > sent);
+/// ```
+pub struct HybridLogicalClock {
+    state: AtomicU64,
+    node: u16,
+    max_drift: Option<Duration>,
+}
+
+impl HybridLogicalClock {
+    /// Create a clock for the node with ID `node`, accepting remote
+    /// timestamps however far ahead they are.
+    pub const fn new(node: u16) -> Self {
+        Self {
+            state: AtomicU64::new(0),
+            node,
+            max_drift: None,
+        }
+    }
+
+    /// Create a clock that rejects remote timestamps more than `max_drift`
+    /// ahead of local physical time, protecting against a peer with a broken
+    /// clock dragging every node into the future.
+    pub const fn with_max_drift(node: u16, max_drift: Duration) -> Self {
+        Self {
+            state: AtomicU64::new(0),
+            node,
+            max_drift: Some(max_drift),
+        }
+    }
+
+    /// This clock's node ID.
+    pub const fn node(&self) -> u16 {
+        self.node
+    }
+
+    /// Timestamp a local or send event.
+    pub fn now(&self) -> Result<HlcTimestamp, TimestampError> {
+        self.now_with_clock(&SystemClock)
+    }
+
+    /// Like [`now()`](Self::now), reading physical time from `clock`.
+    pub fn now_with_clock<C: Clock + ?Sized>(
+        &self,
+        clock: &C,
+    ) -> Result<HlcTimestamp, TimestampError> {
+        let pt = physical_ms(clock)?;
+        self.advance(|l, c| {
+            if pt > l {
+                Ok((pt, 0))
+            } else {
+                Ok((l, bump(c)?))
+            }
+        })
+    }
+
+    /// Merge a timestamp received from another node and timestamp the
+    /// receive event. The result is greater than both `received` and every
+    /// timestamp this clock issued before.
+    ///
+    /// Fails with [`TimestampError::ClockDrift`] if `received` is further
+    /// ahead than the configured maximum drift, and with
+    /// [`TimestampError::LogicalOverflow`] if the logical counter would exceed
+    /// `u16::MAX` within one millisecond.
+    pub fn update(&self, received: HlcTimestamp) -> Result<HlcTimestamp, TimestampError> {
+        self.update_with_clock(received, &SystemClock)
+    }
+
+    /// Like [`update()`](Self::update), reading physical time from `clock`.
+    pub fn update_with_clock<C: Clock + ?Sized>(
+        &self,
+        received: HlcTimestamp,
+        clock: &C,
+    ) -> Result<HlcTimestamp, TimestampError> {
+        let pt = physical_ms(clock)?;
+        if let Some(max) = self.max_drift
+            && received.physical_ms as i128 - pt as i128 > max.whole_milliseconds()
+        {
+            return Err(TimestampError::ClockDrift);
+        }
+        let (ml, mc) = (received.physical_ms, received.logical);
+        self.advance(|l, c| {
+            let new_l = l.max(ml).max(pt);
+            let new_c = if new_l == l && new_l == ml {
+                bump(c.max(mc))?
+            } else if new_l == l {
+                bump(c)?
+            } else if new_l == ml {
+                bump(mc)?
+            } else {
+                0
+            };
+            Ok((new_l, new_c))
+        })
+    }
+
+    /// CAS loop applying `step` to the packed `(physical, logical)` state.
+    fn advance(
+        &self,
+        step: impl Fn(u64, u16) -> Result<(u64, u16), TimestampError>,
+    ) -> Result<HlcTimestamp, TimestampError> {
+        let mut current = self.state.load(Ordering::Acquire);
+        loop {
+            let (l, c) = unpack(current);
+            let (new_l, new_c) = step(l, c)?;
+            let next = pack(new_l, new_c);
+            match self.state.compare_exchange_weak(
+                current,
+                next,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return HlcTimestamp::new(new_l, new_c, self.node),
+                Err(actual) => current = actual,
+            }
+        }
+    }
+}
+
+#[inline]
+fn bump(c: u16) -> Result<u16, TimestampError> {
+    c.checked_add(1).ok_or(TimestampError::LogicalOverflow)
+}
+
+#[inline]
+fn pack(l: u64, c: u16) -> u64 {
+    l << 16 | c as u64
+}
+
+#[inline]
+fn unpack(state: u64) -> (u64, u16) {
+    (state >> 16, state as u16)
+}
+
+fn physical_ms<C: Clock + ?Sized>(clock: &C) -> Result<u64, TimestampError> {
+    let ms = clock.now_utc().unix_timestamp_nanos() / 1_000_000;
+    u64::try_from(ms)
+        .ok()
+        .filter(|&ms| ms <= MAX_PHYSICAL_MS)
+        .ok_or(TimestampError::OutOfRange)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use time::macros::datetime;
+
+    fn clock() -> MockClock {
+        MockClock::new(datetime!(2026-10-16 09:00:00 UTC))
+    }
+
+    #[test]
+    fn test_now_increments_logical_within_millisecond() {
+        let clock = clock();
+        let hlc = HybridLogicalClock::new(7);
+        let a = hlc.now_with_clock(&clock).unwrap();
+        let b = hlc.now_with_clock(&clock).unwrap();
+        assert_eq!(a.logical(), 0);
+        assert_eq!(b.logical(), 1);
+        assert_eq!(a.physical_ms(), b.physical_ms());
+        clock.advance(Duration::MILLISECOND);
+        let c = hlc.now_with_clock(&clock).unwrap();
+        assert_eq!(c.logical(), 0);
+        assert!(a < b && b < c);
+    }
+
+    #[test]
+    fn test_now_survives_clock_regression() {
+        let clock = clock();
+        let hlc = HybridLogicalClock::new(1);
+        let a = hlc.now_with_clock(&clock).unwrap();
+        clock.advance(Duration::seconds(-10));
+        let b = hlc.now_with_clock(&clock).unwrap();
+        assert!(b > a);
+        assert_eq!(b.physical_ms(), a.physical_ms());
+    }
+
+    #[test]
+    fn test_update_from_future_node() {
+        let clock = clock();
+        let local = HybridLogicalClock::new(1);
+        local.now_with_clock(&clock).unwrap();
+        let remote = HlcTimestamp::new(
+            (datetime!(2026-10-16 09:00:05 UTC).unix_timestamp() * 1000) as u64,
+            3,
+            2,
+        )
+        .unwrap();
+        let merged = local.update_with_clock(remote, &clock).unwrap();
+        assert_eq!(merged.physical_ms(), remote.physical_ms());
+        assert_eq!(merged.logical(), 4);
+        assert_eq!(merged.node(), 1);
+        // Subsequent local events stay ahead of the merged remote time.
+        let next = local.now_with_clock(&clock).unwrap();
+        assert!(next > merged);
+    }
+
+    #[test]
+    fn test_update_from_past_node_uses_local_time() {
+        let clock = clock();
+        let local = HybridLogicalClock::new(1);
+        let remote = HlcTimestamp::new(0, 9, 2).unwrap();
+        let merged = local.update_with_clock(remote, &clock).unwrap();
+        assert_eq!(merged.logical(), 0);
+        assert_eq!(merged.datetime(), datetime!(2026-10-16 09:00:00 UTC));
+    }
+
+    #[test]
+    fn test_update_equal_physical_takes_max_logical() {
+        let clock = clock();
+        let local = HybridLogicalClock::new(1);
+        let a = local.now_with_clock(&clock).unwrap(); // logical 0
+        let remote = HlcTimestamp::new(a.physical_ms(), 5, 2).unwrap();
+        let merged = local.update_with_clock(remote, &clock).unwrap();
+        assert_eq!(merged.logical(), 6);
+    }
+
+    #[test]
+    fn test_max_drift_rejects_far_future() {
+        let clock = clock();
+        let local = HybridLogicalClock::with_max_drift(1, Duration::seconds(1));
+        let now_ms = (datetime!(2026-10-16 09:00:00 UTC).unix_timestamp() * 1000) as u64;
+        let ok = HlcTimestamp::new(now_ms + 1000, 0, 2).unwrap();
+        let bad = HlcTimestamp::new(now_ms + 1001, 0, 2).unwrap();
+        assert!(local.update_with_clock(ok, &clock).is_ok());
+        assert_eq!(
+            local.update_with_clock(bad, &clock),
+            Err(TimestampError::ClockDrift)
+        );
+    }
+
+    #[test]
+    fn test_logical_overflow() {
+        let clock = clock();
+        let local = HybridLogicalClock::new(1);
+        let a = local.now_with_clock(&clock).unwrap();
+        let remote = HlcTimestamp::new(a.physical_ms(), u16::MAX, 2).unwrap();
+        assert_eq!(
+            local.update_with_clock(remote, &clock),
+            Err(TimestampError::LogicalOverflow)
+        );
+    }
+
+    #[test]
+    fn test_cross_node_ordering_is_deterministic() {
+        let a = HlcTimestamp::new(1000, 1, 1).unwrap();
+        let b = HlcTimestamp::new(1000, 1, 2).unwrap();
+        let c = HlcTimestamp::new(1000, 2, 0).unwrap();
+        assert!(a < b && b < c);
+        assert!(a.to_string() < b.to_string() && b.to_string() < c.to_string());
+    }
+
+    #[test]
+    fn test_string_roundtrip_and_serde() {
+        let ts = HlcTimestamp::new(1_792_141_200_123, 0xab, 0xffff).unwrap();
+        let s = ts.to_string();
+        assert_eq!(s, "2026-10-16T09:00:00.123Z-00ab-ffff");
+        assert_eq!(s.parse::<HlcTimestamp>().unwrap(), ts);
+        let json = serde_json::to_string(&ts).unwrap();
+        assert_eq!(json, format!("\"{s}\""));
+        assert_eq!(serde_json::from_str::<HlcTimestamp>(&json).unwrap(), ts);
+        assert!(serde_json::from_str::<HlcTimestamp>("\"2026-10-16T09:00:00.123Z\"").is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "2026-10-16T09:00:00.123Z-00AB-ffff".parse::<HlcTimestamp>(),
+            Err(TimestampError::InvalidLogical)
+        );
+        assert_eq!(
+            "2026-10-16T09:00:00.123Z-00ab-fffg".parse::<HlcTimestamp>(),
+            Err(TimestampError::InvalidNode)
+        );
+        assert_eq!(
+            "2026-10-16T09:00:00.123Z-00ab".parse::<HlcTimestamp>(),
+            Err(TimestampError::InvalidLength(29))
+        );
+        assert_eq!(
+            "2026-10-16T09:00:00.123Z_00ab-ffff".parse::<HlcTimestamp>(),
+            Err(TimestampError::InvalidSeparator {
+                index: 24,
+                expected: '-'
+            })
+        );
+    }
+
+    #[test]
+    fn test_concurrent_now_is_unique() {
+        use std::collections::HashSet;
+        use std::sync::Arc;
+        let hlc = Arc::new(HybridLogicalClock::new(1));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let hlc = Arc::clone(&hlc);
+                std::thread::spawn(move || {
+                    (0..1000).map(|_| hlc.now().unwrap()).collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        let mut seen = HashSet::new();
+        for h in handles {
+            for ts in h.join().unwrap() {
+                assert!(seen.insert(ts));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn string_roundtrip(ms in 0u64..=253_402_300_799_999, logical: u16, node: u16) {
+            let ts = HlcTimestamp::new(ms, logical, node).unwrap();
+            prop_assert_eq!(ts.to_string().parse::<HlcTimestamp>().unwrap(), ts);
+        }
+
+        #[test]
+        fn string_order_matches_ord(
+            a in (0u64..=253_402_300_799_999, any::<u16>(), any::<u16>()),
+            b in (0u64..=253_402_300_799_999, any::<u16>(), any::<u16>()),
+        ) {
+            let a = HlcTimestamp::new(a.0, a.1, a.2).unwrap();
+            let b = HlcTimestamp::new(b.0, b.1, b.2).unwrap();
+            prop_assert_eq!(a.cmp(&b), a.to_string().cmp(&b.to_string()));
+        }
+    }
+}
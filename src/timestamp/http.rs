@@ -0,0 +1,410 @@
+//! HTTP-date (RFC 9110 §5.6.7) formatting and parsing.
+//!
+//! Generation always uses the preferred IMF-fixdate form
+//! (`Sun, 06 Nov 1994 08:49:37 GMT`). Parsing additionally accepts the two
+//! obsolete forms that HTTP recipients are required to understand: RFC 850
+//! (`Sunday, 06-Nov-94 08:49:37 GMT`) and ANSI C `asctime()`
+//! (`Sun Nov  6 08:49:37 1994`).
+
+use super::{
+    TimestampError, expect_separator, format_utc_into, get_utc_formatter, parse_digits, parse_hms,
+    parse_utc_timestamp,
+};
+use crate::clock::{Clock, SystemClock};
+use time::{
+    Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday,
+    format_description::FormatItem, macros::format_description,
+};
+
+/// Length in bytes of an IMF-fixdate: `Sun, 06 Nov 1994 08:49:37 GMT`.
+const IMF_FIXDATE_LEN: usize = 29;
+
+/// Length in bytes of an `asctime()` date: `Sun Nov  6 08:49:37 1994`.
+const ASCTIME_LEN: usize = 24;
+
+const DAY_NAMES: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+const DAY_NAMES_LONG: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+// ---------------------------------------------------------------------------
+// Formatting
+// ---------------------------------------------------------------------------
+
+/// Returns a static reference to the IMF-fixdate format description.
+///
+/// The format is `Sun, 06 Nov 1994 08:49:37 GMT`. Like [`get_utc_formatter()`],
+/// it prints the value's own offset fields, so only use it with UTC values;
+/// [`format_http_date()`] converts for you.
+pub fn get_http_date_formatter() -> &'static [FormatItem<'static>] {
+    format_description!(
+        "[weekday repr:short], [day padding:zero] [month repr:short] [year] [hour padding:zero]:[minute padding:zero]:[second padding:zero] GMT"
+    )
+}
+
+/// Format an `OffsetDateTime` as an IMF-fixdate into a fixed-size buffer.
+///
+/// Non-UTC inputs are converted to UTC first; sub-second precision is dropped.
+///
+/// # Panics
+///
+/// Panics if the UTC year is outside `0000..=9999`.
+pub fn format_http_date_into(dt: &OffsetDateTime) -> [u8; 29] {
+    let dt = dt.to_offset(UtcOffset::UTC);
+    // Validates the year range and lays out the numeric fields for us.
+    let utc = format_utc_into(&dt);
+
+    let mut buf = [b' '; IMF_FIXDATE_LEN];
+    buf[0..3]
+        .copy_from_slice(DAY_NAMES[dt.weekday().number_days_from_monday() as usize].as_bytes());
+    buf[3] = b',';
+    buf[5..7].copy_from_slice(&utc[8..10]);
+    buf[8..11].copy_from_slice(MONTH_NAMES[dt.month() as usize - 1].as_bytes());
+    buf[12..16].copy_from_slice(&utc[0..4]);
+    buf[17..25].copy_from_slice(&utc[11..19]);
+    buf[26..29].copy_from_slice(b"GMT");
+    buf
+}
+
+/// Format an `OffsetDateTime` as an IMF-fixdate string.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::format_http_date;
+/// use time::macros::datetime;
+///
+/// let s = format_http_date(&datetime!(1994-11-06 08:49:37 UTC));
+/// assert_eq!(s, "Sun, 06 Nov 1994 08:49:37 GMT");
+/// ```
+pub fn format_http_date(dt: &OffsetDateTime) -> String {
+    let buf = format_http_date_into(dt);
+    // All bytes written are ASCII.
+    String::from_utf8(buf.to_vec()).unwrap()
+}
+
+/// Returns the current time as an IMF-fixdate, e.g. for a `Date` header.
+pub fn get_http_date() -> String {
+    get_http_date_with_clock(&SystemClock)
+}
+
+/// Like [`get_http_date()`], reading the time from `clock`.
+pub fn get_http_date_with_clock<C: Clock + ?Sized>(clock: &C) -> String {
+    format_http_date(&clock.now_utc())
+}
+
+// ---------------------------------------------------------------------------
+// Parsing
+// ---------------------------------------------------------------------------
+
+/// Parse an HTTP-date in any of the three forms RFC 9110 requires recipients
+/// to accept.
+///
+/// Matching is case-sensitive, as the grammar specifies. The weekday must agree
+/// with the date. For the two-digit RFC 850 year, a year that would appear more
+/// than 50 years in the future is taken to be in the past century, per RFC 9110.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::parse_http_date;
+///
+/// let a = parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+/// let b = parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT").unwrap();
+/// let c = parse_http_date("Sun Nov  6 08:49:37 1994").unwrap();
+/// assert_eq!(a, b);
+/// assert_eq!(b, c);
+/// ```
+pub fn parse_http_date(s: &str) -> Result<OffsetDateTime, TimestampError> {
+    parse_http_date_with_clock(s, &SystemClock)
+}
+
+/// Like [`parse_http_date()`], reading the current year for the RFC 850
+/// two-digit year window from `clock`.
+pub fn parse_http_date_with_clock<C: Clock + ?Sized>(
+    s: &str,
+    clock: &C,
+) -> Result<OffsetDateTime, TimestampError> {
+    let b = s.as_bytes();
+    if b.len() == IMF_FIXDATE_LEN && b[3] == b',' {
+        parse_imf_fixdate(b)
+    } else if b.len() == ASCTIME_LEN && b[3] == b' ' {
+        parse_asctime(b)
+    } else if let Some(comma) = b.iter().position(|&c| c == b',')
+        && comma > 3
+    {
+        parse_rfc850(b, comma, clock.now_utc().year())
+    } else {
+        Err(TimestampError::UnrecognizedFormat)
+    }
+}
+
+/// Convert an HTTP-date (any accepted form) to the canonical `YYYY-MM-DDTHH:MM:SSZ` string.
+pub fn http_date_to_utc_timestamp(s: &str) -> Result<String, TimestampError> {
+    let dt = parse_http_date(s)?;
+    dt.format(get_utc_formatter())
+        .map_err(|_| TimestampError::OutOfRange)
+}
+
+/// Convert a canonical `YYYY-MM-DDTHH:MM:SSZ` string to an IMF-fixdate.
+pub fn utc_timestamp_to_http_date(s: &str) -> Result<String, TimestampError> {
+    parse_utc_timestamp(s).map(|dt| format_http_date(&dt))
+}
+
+fn parse_imf_fixdate(b: &[u8]) -> Result<OffsetDateTime, TimestampError> {
+    // Sun, 06 Nov 1994 08:49:37 GMT
+    let weekday = parse_weekday(&b[0..3], &DAY_NAMES)?;
+    expect_separator(b, 4, b' ')?;
+    let day = parse_digits(&b[5..7]).ok_or(TimestampError::InvalidDay)?;
+    expect_separator(b, 7, b' ')?;
+    let month = parse_month(&b[8..11])?;
+    expect_separator(b, 11, b' ')?;
+    let year = parse_digits(&b[12..16]).ok_or(TimestampError::InvalidYear)?;
+    expect_separator(b, 16, b' ')?;
+    let time = parse_hms(b, 17)?;
+    expect_separator(b, 25, b' ')?;
+    expect_gmt(b, 26)?;
+    build(year as i32, month, day, time, weekday)
+}
+
+fn parse_rfc850(
+    b: &[u8],
+    comma: usize,
+    current_year: i32,
+) -> Result<OffsetDateTime, TimestampError> {
+    // Sunday, 06-Nov-94 08:49:37 GMT
+    let weekday = parse_weekday(&b[..comma], &DAY_NAMES_LONG)?;
+    let rest = comma + 1;
+    if b.len() != rest + 23 {
+        return Err(TimestampError::InvalidLength(b.len()));
+    }
+    expect_separator(b, rest, b' ')?;
+    let day = parse_digits(&b[rest + 1..rest + 3]).ok_or(TimestampError::InvalidDay)?;
+    expect_separator(b, rest + 3, b'-')?;
+    let month = parse_month(&b[rest + 4..rest + 7])?;
+    expect_separator(b, rest + 7, b'-')?;
+    let yy = parse_digits(&b[rest + 8..rest + 10]).ok_or(TimestampError::InvalidYear)? as i32;
+    expect_separator(b, rest + 10, b' ')?;
+    let time = parse_hms(b, rest + 11)?;
+    expect_separator(b, rest + 19, b' ')?;
+    expect_gmt(b, rest + 20)?;
+
+    let mut year = current_year - current_year.rem_euclid(100) + yy;
+    if year > current_year + 50 {
+        year -= 100;
+    }
+    build(year, month, day, time, weekday)
+}
+
+fn parse_asctime(b: &[u8]) -> Result<OffsetDateTime, TimestampError> {
+    // Sun Nov  6 08:49:37 1994
+    let weekday = parse_weekday(&b[0..3], &DAY_NAMES)?;
+    let month = parse_month(&b[4..7])?;
+    expect_separator(b, 7, b' ')?;
+    let day = match b[8] {
+        b' ' => parse_digits(&b[9..10]),
+        _ => parse_digits(&b[8..10]),
+    }
+    .ok_or(TimestampError::InvalidDay)?;
+    expect_separator(b, 10, b' ')?;
+    let time = parse_hms(b, 11)?;
+    expect_separator(b, 19, b' ')?;
+    let year = parse_digits(&b[20..24]).ok_or(TimestampError::InvalidYear)?;
+    build(year as i32, month, day, time, weekday)
+}
+
+fn parse_weekday(name: &[u8], names: &[&str; 7]) -> Result<Weekday, TimestampError> {
+    let index = names
+        .iter()
+        .position(|n| n.as_bytes() == name)
+        .ok_or(TimestampError::InvalidWeekday)?;
+    Ok(Weekday::Monday.nth_next(index as u8))
+}
+
+fn parse_month(name: &[u8]) -> Result<Month, TimestampError> {
+    let index = MONTH_NAMES
+        .iter()
+        .position(|n| n.as_bytes() == name)
+        .ok_or(TimestampError::InvalidMonth)?;
+    Month::try_from(index as u8 + 1).map_err(|_| TimestampError::InvalidMonth)
+}
+
+fn expect_gmt(b: &[u8], at: usize) -> Result<(), TimestampError> {
+    expect_separator(b, at, b'G')?;
+    expect_separator(b, at + 1, b'M')?;
+    expect_separator(b, at + 2, b'T')
+}
+
+fn build(
+    year: i32,
+    month: Month,
+    day: u32,
+    time: Time,
+    weekday: Weekday,
+) -> Result<OffsetDateTime, TimestampError> {
+    let date =
+        Date::from_calendar_date(year, month, day as u8).map_err(|_| TimestampError::InvalidDay)?;
+    if date.weekday() != weekday {
+        return Err(TimestampError::InvalidWeekday);
+    }
+    Ok(PrimitiveDateTime::new(date, time).assume_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::FixedClock;
+    use time::macros::datetime;
+
+    const EXAMPLE: OffsetDateTime = datetime!(1994-11-06 08:49:37 UTC);
+
+    #[test]
+    fn test_format_http_date() {
+        assert_eq!(format_http_date(&EXAMPLE), "Sun, 06 Nov 1994 08:49:37 GMT");
+        assert_eq!(
+            EXAMPLE.format(get_http_date_formatter()).unwrap(),
+            "Sun, 06 Nov 1994 08:49:37 GMT"
+        );
+    }
+
+    #[test]
+    fn test_format_http_date_converts_to_utc() {
+        let dt = datetime!(1994-11-06 10:49:37.5 +02:00);
+        assert_eq!(format_http_date(&dt), "Sun, 06 Nov 1994 08:49:37 GMT");
+    }
+
+    #[test]
+    fn test_get_http_date_with_clock() {
+        let clock = crate::clock::FixedClock::new(EXAMPLE);
+        assert_eq!(
+            get_http_date_with_clock(&clock),
+            "Sun, 06 Nov 1994 08:49:37 GMT"
+        );
+        assert_eq!(get_http_date().len(), IMF_FIXDATE_LEN);
+    }
+
+    #[test]
+    fn test_parse_all_three_forms() {
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Ok(EXAMPLE)
+        );
+        assert_eq!(
+            parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"),
+            Ok(EXAMPLE)
+        );
+        assert_eq!(parse_http_date("Sun Nov  6 08:49:37 1994"), Ok(EXAMPLE));
+        assert_eq!(
+            parse_http_date("Wed Nov 16 08:49:37 1994"),
+            Ok(datetime!(1994-11-16 08:49:37 UTC))
+        );
+    }
+
+    #[test]
+    fn test_rfc850_two_digit_year_window() {
+        let clock = FixedClock::new(datetime!(2026-10-16 09:00:00 UTC));
+        let year = |s: &str| parse_http_date_with_clock(s, &clock).unwrap().year();
+        assert_eq!(year("Sunday, 06-Nov-94 08:49:37 GMT"), 1994);
+        assert_eq!(year("Friday, 16-Oct-26 00:00:00 GMT"), 2026);
+        // 2076 is exactly 50 years ahead: still in the future window.
+        assert_eq!(year("Thursday, 31-Dec-76 00:00:00 GMT"), 2076);
+        assert_eq!(year("Saturday, 01-Jan-77 00:00:00 GMT"), 1977);
+
+        // A year later the window has moved on.
+        let clock = FixedClock::new(datetime!(2027-01-01 00:00:00 UTC));
+        assert_eq!(
+            parse_http_date_with_clock("Friday, 01-Jan-77 00:00:00 GMT", &clock)
+                .unwrap()
+                .year(),
+            2077
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_wrong_weekday() {
+        assert_eq!(
+            parse_http_date("Mon, 06 Nov 1994 08:49:37 GMT"),
+            Err(TimestampError::InvalidWeekday)
+        );
+        assert_eq!(
+            parse_http_date("Sund, 06 Nov 1994 08:49:37 GMT"),
+            Err(TimestampError::InvalidWeekday)
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_components() {
+        assert_eq!(
+            parse_http_date("Sun, 06 nov 1994 08:49:37 GMT"),
+            Err(TimestampError::InvalidMonth)
+        );
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 UTC"),
+            Err(TimestampError::InvalidSeparator {
+                index: 26,
+                expected: 'G'
+            })
+        );
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 24:49:37 GMT"),
+            Err(TimestampError::InvalidHour)
+        );
+        assert_eq!(
+            parse_http_date("Sun, 31 Nov 1994 08:49:37 GMT"),
+            Err(TimestampError::InvalidDay)
+        );
+        assert_eq!(
+            parse_http_date("1994-11-06T08:49:37Z"),
+            Err(TimestampError::UnrecognizedFormat)
+        );
+        assert_eq!(
+            parse_http_date("Sunday, 06-Nov-1994 08:49:37 GMT"),
+            Err(TimestampError::InvalidLength(32))
+        );
+    }
+
+    #[test]
+    fn test_conversions_to_and_from_canonical() {
+        assert_eq!(
+            http_date_to_utc_timestamp("Sun Nov  6 08:49:37 1994").unwrap(),
+            "1994-11-06T08:49:37Z"
+        );
+        assert_eq!(
+            utc_timestamp_to_http_date("1994-11-06T08:49:37Z").unwrap(),
+            "Sun, 06 Nov 1994 08:49:37 GMT"
+        );
+        assert!(utc_timestamp_to_http_date("Sun, 06 Nov 1994 08:49:37 GMT").is_err());
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn format_parse_roundtrip(secs in -62_167_219_200i64..=253_402_300_799) {
+            let dt = OffsetDateTime::from_unix_timestamp(secs).unwrap();
+            let s = format_http_date(&dt);
+            prop_assert_eq!(&s, &dt.format(get_http_date_formatter()).unwrap());
+            prop_assert_eq!(parse_http_date(&s).unwrap(), dt);
+        }
+
+        #[test]
+        fn parse_never_panics(s in "\\PC{0,40}") {
+            let _ = parse_http_date(&s);
+        }
+    }
+}
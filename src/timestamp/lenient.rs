@@ -0,0 +1,423 @@
+//! Lenient ingestion of the ISO 8601 / RFC 3339 shapes found in CSVs and
+//! logs, with canonicalization to `YYYY-MM-DDTHH:MM:SSZ`.
+
+use super::{
+    TimestampError, format_utc_into, parse_digits, parse_fraction, parse_offset, parse_ymd,
+};
+use time::{OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+/// How [`parse_lenient`] treats inputs without a zone designator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ParseMode {
+    /// Assume UTC for inputs without `Z` or an offset, including date-only
+    /// inputs (read as midnight UTC).
+    #[default]
+    Lenient,
+    /// Reject inputs without `Z` or an offset with
+    /// [`TimestampError::MissingOffset`], since their instant is ambiguous.
+    Strict,
+}
+
+/// Character between the date and time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateTimeSeparator {
+    /// `T`.
+    T,
+    /// `t`.
+    LowercaseT,
+    /// A single space.
+    Space,
+}
+
+/// Zone designator at the end of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ZoneDesignator {
+    /// `Z`.
+    Z,
+    /// `z`.
+    LowercaseZ,
+    /// A numeric offset: `±HH:MM`, `±HHMM` or `±HH`.
+    Offset(UtcOffset),
+    /// No designator; the instant is ambiguous.
+    Missing,
+}
+
+/// Which variant of the timestamp syntax an input matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimestampVariant {
+    separator: Option<DateTimeSeparator>,
+    has_seconds: bool,
+    fraction_digits: usize,
+    zone: ZoneDesignator,
+}
+
+impl TimestampVariant {
+    /// The date/time separator, or `None` for a date-only input.
+    pub const fn separator(&self) -> Option<DateTimeSeparator> {
+        self.separator
+    }
+
+    /// Whether the input was a date only (`YYYY-MM-DD`).
+    pub const fn is_date_only(&self) -> bool {
+        self.separator.is_none()
+    }
+
+    /// Whether the time included seconds (`HH:MM:SS` rather than `HH:MM`).
+    pub const fn has_seconds(&self) -> bool {
+        self.has_seconds
+    }
+
+    /// Number of fractional-second digits, `0` if there were none.
+    pub const fn fraction_digits(&self) -> usize {
+        self.fraction_digits
+    }
+
+    /// The zone designator.
+    pub const fn zone(&self) -> ZoneDesignator {
+        self.zone
+    }
+
+    /// Whether the input was already in the canonical `YYYY-MM-DDTHH:MM:SSZ` form.
+    pub const fn is_canonical(&self) -> bool {
+        matches!(self.separator, Some(DateTimeSeparator::T))
+            && self.has_seconds
+            && self.fraction_digits == 0
+            && matches!(self.zone, ZoneDesignator::Z)
+    }
+}
+
+/// Result of [`parse_lenient`]: the instant in UTC and the variant matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LenientTimestamp {
+    datetime: OffsetDateTime,
+    variant: TimestampVariant,
+}
+
+impl LenientTimestamp {
+    /// The parsed instant, in UTC.
+    pub const fn datetime(&self) -> OffsetDateTime {
+        self.datetime
+    }
+
+    /// The variant the input matched.
+    pub const fn variant(&self) -> TimestampVariant {
+        self.variant
+    }
+
+    /// The canonical `YYYY-MM-DDTHH:MM:SSZ` string, as produced by
+    /// [`get_utc_formatter()`](super::get_utc_formatter). Sub-second
+    /// precision is truncated.
+    pub fn canonical(&self) -> String {
+        let buf = format_utc_into(&self.datetime);
+        // All bytes written are ASCII.
+        String::from_utf8(buf.to_vec()).unwrap()
+    }
+}
+
+/// Parse a timestamp in any of the common ISO 8601 / RFC 3339 variants.
+///
+/// Accepted, after trimming surrounding ASCII whitespace:
+///
+/// | Part        | Forms                                             |
+/// |-------------|---------------------------------------------------|
+/// | date        | `YYYY-MM-DD` (may stand alone)                    |
+/// | separator   | `T`, `t` or a space                               |
+/// | time        | `HH:MM` or `HH:MM:SS`                             |
+/// | fraction    | `.` or `,` then 1+ digits, after seconds only     |
+/// | zone        | `Z`, `z`, `±HH:MM`, `±HHMM`, `±HH`, or nothing    |
+///
+/// Fractions beyond nine digits are truncated; `-00:00` is read as UTC.
+/// The result is converted to UTC, and fails with
+/// [`TimestampError::OutOfRange`] if that leaves years `0000..=9999`.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::{ParseMode, ZoneDesignator, parse_lenient};
+///
+/// let ts = parse_lenient("2026-10-16 11:00:00.5+02:00", ParseMode::Lenient).unwrap();
+/// assert_eq!(ts.canonical(), "2026-10-16T09:00:00Z");
+/// assert_eq!(ts.variant().fraction_digits(), 1);
+///
+/// let ts = parse_lenient("2026-10-16", ParseMode::Lenient).unwrap();
+/// assert!(ts.variant().is_date_only());
+/// assert_eq!(ts.variant().zone(), ZoneDesignator::Missing);
+///
+/// assert!(parse_lenient("2026-10-16 09:00", ParseMode::Strict).is_err());
+/// ```
+pub fn parse_lenient(s: &str, mode: ParseMode) -> Result<LenientTimestamp, TimestampError> {
+    let b = s.trim_ascii().as_bytes();
+    if b.len() < 10 {
+        return Err(TimestampError::InvalidLength(b.len()));
+    }
+    let date = parse_ymd(b, 0)?;
+
+    let (separator, time, has_seconds, fraction_digits, zone) = if b.len() == 10 {
+        (None, Time::MIDNIGHT, false, 0, ZoneDesignator::Missing)
+    } else {
+        let separator = match b[10] {
+            b'T' => DateTimeSeparator::T,
+            b't' => DateTimeSeparator::LowercaseT,
+            b' ' => DateTimeSeparator::Space,
+            _ => {
+                return Err(TimestampError::InvalidSeparator {
+                    index: 10,
+                    expected: 'T',
+                });
+            }
+        };
+        if b.len() < 16 {
+            return Err(TimestampError::InvalidLength(b.len()));
+        }
+        if b[13] != b':' {
+            return Err(TimestampError::InvalidSeparator {
+                index: 13,
+                expected: ':',
+            });
+        }
+        let hour = parse_digits(&b[11..13])
+            .filter(|&h| h < 24)
+            .ok_or(TimestampError::InvalidHour)?;
+        let minute = parse_digits(&b[14..16])
+            .filter(|&m| m < 60)
+            .ok_or(TimestampError::InvalidMinute)?;
+
+        let mut i = 16;
+        let mut second = 0;
+        let mut nanos = 0;
+        let mut fraction_digits = 0;
+        let has_seconds = b.get(i) == Some(&b':');
+        if has_seconds {
+            second = b
+                .get(i + 1..i + 3)
+                .and_then(parse_digits)
+                .filter(|&s| s < 60)
+                .ok_or(TimestampError::InvalidSecond)?;
+            i += 3;
+            if matches!(b.get(i), Some(b'.' | b',')) {
+                let start = i + 1;
+                fraction_digits = b[start..].iter().take_while(|c| c.is_ascii_digit()).count();
+                nanos = parse_fraction(&b[start..start + fraction_digits.min(9)])?;
+                i = start + fraction_digits;
+            }
+        }
+        let zone = parse_zone(&b[i..])?;
+        // Components were range-checked above, so this cannot fail.
+        let time = Time::from_hms_nano(hour as u8, minute as u8, second as u8, nanos)
+            .map_err(|_| TimestampError::InvalidSecond)?;
+        (Some(separator), time, has_seconds, fraction_digits, zone)
+    };
+
+    let offset = match zone {
+        ZoneDesignator::Z | ZoneDesignator::LowercaseZ => UtcOffset::UTC,
+        ZoneDesignator::Offset(offset) => offset,
+        ZoneDesignator::Missing => match mode {
+            ParseMode::Lenient => UtcOffset::UTC,
+            ParseMode::Strict => return Err(TimestampError::MissingOffset),
+        },
+    };
+    let datetime = PrimitiveDateTime::new(date, time)
+        .assume_offset(offset)
+        .checked_to_offset(UtcOffset::UTC)
+        .ok_or(TimestampError::OutOfRange)?;
+    if !(0..=9999).contains(&datetime.year()) {
+        return Err(TimestampError::OutOfRange);
+    }
+
+    Ok(LenientTimestamp {
+        datetime,
+        variant: TimestampVariant {
+            separator,
+            has_seconds,
+            fraction_digits,
+            zone,
+        },
+    })
+}
+
+/// Parse with [`parse_lenient`] and return the canonical
+/// `YYYY-MM-DDTHH:MM:SSZ` string.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::{ParseMode, canonicalize};
+///
+/// assert_eq!(
+///     canonicalize("2026-10-16t09:00:00.123z", ParseMode::Strict).unwrap(),
+///     "2026-10-16T09:00:00Z"
+/// );
+/// ```
+pub fn canonicalize(s: &str, mode: ParseMode) -> Result<String, TimestampError> {
+    parse_lenient(s, mode).map(|ts| ts.canonical())
+}
+
+/// Parse the zone designator, which must make up the whole of `b`.
+fn parse_zone(b: &[u8]) -> Result<ZoneDesignator, TimestampError> {
+    match b {
+        [] => Ok(ZoneDesignator::Missing),
+        [b'Z'] => Ok(ZoneDesignator::Z),
+        [b'z'] => Ok(ZoneDesignator::LowercaseZ),
+        _ => parse_offset(b, true).map(ZoneDesignator::Offset),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::{datetime, offset};
+
+    fn lenient(s: &str) -> Result<String, TimestampError> {
+        canonicalize(s, ParseMode::Lenient)
+    }
+
+    #[test]
+    fn test_variants_canonicalize() {
+        let expected = "2026-10-16T09:00:00Z";
+        for input in [
+            "2026-10-16T09:00:00Z",
+            "2026-10-16t09:00:00z",
+            "2026-10-16 09:00:00Z",
+            "2026-10-16T09:00:00",
+            "2026-10-16 09:00",
+            "2026-10-16T09:00Z",
+            "2026-10-16T09:00:00.999Z",
+            "2026-10-16T09:00:00,5Z",
+            "2026-10-16T11:00:00+02:00",
+            "2026-10-16T11:00:00+0200",
+            "2026-10-16 11:00:00.123+02",
+            "2026-10-16T09:00:00-00:00",
+            "  2026-10-16T09:00:00Z\t",
+        ] {
+            assert_eq!(lenient(input).as_deref(), Ok(expected), "{input:?}");
+        }
+        assert_eq!(lenient("2026-10-16").as_deref(), Ok("2026-10-16T00:00:00Z"));
+    }
+
+    #[test]
+    fn test_reports_variant() {
+        let v = parse_lenient("2026-10-16T09:00:00Z", ParseMode::Strict)
+            .unwrap()
+            .variant();
+        assert!(v.is_canonical());
+
+        let v = parse_lenient("2026-10-16 09:00", ParseMode::Lenient)
+            .unwrap()
+            .variant();
+        assert_eq!(v.separator(), Some(DateTimeSeparator::Space));
+        assert!(!v.has_seconds());
+        assert_eq!(v.zone(), ZoneDesignator::Missing);
+        assert!(!v.is_canonical());
+
+        let ts =
+            parse_lenient("2026-10-16t04:30:00.123456789123-04:30", ParseMode::Strict).unwrap();
+        let v = ts.variant();
+        assert_eq!(v.separator(), Some(DateTimeSeparator::LowercaseT));
+        assert_eq!(v.fraction_digits(), 12);
+        assert_eq!(v.zone(), ZoneDesignator::Offset(offset!(-04:30)));
+        assert_eq!(
+            ts.datetime(),
+            datetime!(2026-10-16 09:00:00.123_456_789 UTC)
+        );
+    }
+
+    #[test]
+    fn test_strict_rejects_missing_offset() {
+        for input in ["2026-10-16", "2026-10-16 09:00:00", "2026-10-16T09:00"] {
+            assert_eq!(
+                parse_lenient(input, ParseMode::Strict),
+                Err(TimestampError::MissingOffset),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(lenient("2026-10"), Err(TimestampError::InvalidLength(7)));
+        assert_eq!(
+            lenient("2026-10-16X09:00:00Z"),
+            Err(TimestampError::InvalidSeparator {
+                index: 10,
+                expected: 'T'
+            })
+        );
+        assert_eq!(
+            lenient("2026-10-16T09"),
+            Err(TimestampError::InvalidLength(13))
+        );
+        assert_eq!(
+            lenient("2026-10-16T25:00Z"),
+            Err(TimestampError::InvalidHour)
+        );
+        assert_eq!(
+            lenient("2026-10-16T09:00:6"),
+            Err(TimestampError::InvalidSecond)
+        );
+        assert_eq!(
+            lenient("2026-10-16T09:00:00.Z"),
+            Err(TimestampError::InvalidFraction)
+        );
+        assert_eq!(
+            lenient("2026-10-16T09:00.5Z"),
+            Err(TimestampError::InvalidOffset)
+        );
+        assert_eq!(
+            lenient("2026-10-16T09:00:00+2:00"),
+            Err(TimestampError::InvalidOffset)
+        );
+        assert_eq!(
+            lenient("2026-10-16T09:00:00 UTC"),
+            Err(TimestampError::InvalidOffset)
+        );
+        assert_eq!(
+            lenient("0000-01-01T00:00:00+01:00"),
+            Err(TimestampError::OutOfRange)
+        );
+        assert_eq!(
+            lenient("9999-12-31T23:59:59-23:59"),
+            Err(TimestampError::OutOfRange)
+        );
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(s in "\\PC{0,40}") {
+            let _ = parse_lenient(&s, ParseMode::Lenient);
+            let _ = parse_lenient(&s, ParseMode::Strict);
+        }
+
+        #[test]
+        fn parse_near_valid_never_panics(
+            s in "[0-9]{4}-[0-9]{2}-[0-9]{2}[Tt ][0-9:.,]{0,15}[-+Zz0-9:]{0,6}",
+        ) {
+            let _ = parse_lenient(&s, ParseMode::Lenient);
+        }
+
+        #[test]
+        fn parse_range_edges_never_panic(
+            year in prop_oneof![Just(0u32), Just(9999), 0u32..=9999],
+            month in prop_oneof![Just(1u32), Just(12), 1u32..=12],
+            day in prop_oneof![Just(1u32), Just(31), 1u32..=28],
+            hour in 0u32..24,
+            minute in 0u32..60,
+            sign in prop::sample::select(vec!['+', '-']),
+            offset_hours in 0u32..24,
+            offset_minutes in 0u32..60,
+        ) {
+            let s = format!(
+                "{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:00\
+                 {sign}{offset_hours:02}:{offset_minutes:02}"
+            );
+            if let Ok(ts) = parse_lenient(&s, ParseMode::Strict) {
+                prop_assert!((0..=9999).contains(&ts.datetime().year()));
+            }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! Compile-time validated timestamp literals: [`utc!`](crate::timestamp::utc).
+
+use super::{TimestampError, UTC_TIMESTAMP_LEN, expect_separator, parse_hms, parse_ymd};
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+/// Const-evaluable form of [`parse_utc_timestamp`](super::parse_utc_timestamp),
+/// which delegates here: it accepts exactly the canonical
+/// `YYYY-MM-DDTHH:MM:SSZ` form.
+///
+/// Usable in `const` items; see [`utc!`](crate::timestamp::utc) for a macro
+/// that turns a bad literal into a build error.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::parse_utc_timestamp_const;
+/// use time::OffsetDateTime;
+///
+/// const EPOCH: OffsetDateTime = match parse_utc_timestamp_const("1970-01-01T00:00:00Z") {
+///     Ok(dt) => dt,
+///     Err(_) => panic!("bad literal"),
+/// };
+/// assert_eq!(EPOCH, OffsetDateTime::UNIX_EPOCH);
+/// ```
+pub const fn parse_utc_timestamp_const(s: &str) -> Result<OffsetDateTime, TimestampError> {
+    let b = s.as_bytes();
+    if b.len() != UTC_TIMESTAMP_LEN {
+        return Err(TimestampError::InvalidLength(b.len()));
+    }
+
+    let separators: [(usize, u8); 6] = [
+        (4, b'-'),
+        (7, b'-'),
+        (10, b'T'),
+        (13, b':'),
+        (16, b':'),
+        (19, b'Z'),
+    ];
+    let mut i = 0;
+    while i < separators.len() {
+        if let Err(e) = expect_separator(b, separators[i].0, separators[i].1) {
+            return Err(e);
+        }
+        i += 1;
+    }
+
+    let date = match parse_ymd(b, 0) {
+        Ok(date) => date,
+        Err(e) => return Err(e),
+    };
+    let time = match parse_hms(b, 11) {
+        Ok(time) => time,
+        Err(e) => return Err(e),
+    };
+    Ok(PrimitiveDateTime::new(date, time).assume_utc())
+}
+
+/// A canonical UTC timestamp literal, validated at compile time.
+///
+/// Expands to an `OffsetDateTime`. A literal that is not in the exact
+/// `YYYY-MM-DDTHH:MM:SSZ` form fails the build.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::utc;
+///
+/// let dt = utc!("2026-01-01T00:00:00Z");
+/// assert_eq!(dt.unix_timestamp(), 1_767_225_600);
+///
+/// const RELEASE: time::OffsetDateTime = utc!("2026-10-16T09:00:00Z");
+/// assert_eq!(RELEASE.hour(), 9);
+/// ```
+///
+/// ```compile_fail
+/// // February 30th does not exist.
+/// let dt = kiters::timestamp::utc!("2026-02-30T00:00:00Z");
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __timestamp_utc {
+    ($s:literal) => {
+        const {
+            match $crate::timestamp::parse_utc_timestamp_const($s) {
+                ::core::result::Result::Ok(dt) => dt,
+                ::core::result::Result::Err(_) => ::core::panic!(::core::concat!(
+                    "invalid canonical UTC timestamp literal: ",
+                    $s
+                )),
+            }
+        }
+    };
+}
+
+#[doc(inline)]
+pub use crate::__timestamp_utc as utc;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn test_macro() {
+        assert_eq!(
+            utc!("2023-10-27T10:00:00Z"),
+            datetime!(2023-10-27 10:00:00 UTC)
+        );
+        const LEAP_DAY: OffsetDateTime = utc!("2024-02-29T23:59:59Z");
+        assert_eq!(LEAP_DAY, datetime!(2024-02-29 23:59:59 UTC));
+    }
+}
@@ -0,0 +1,275 @@
+//! Strictly monotonic timestamps that survive same-instant calls and
+//! wall-clock regressions.
+
+use super::{format_utc_micros_into, format_utc_millis_into, format_utc_nanos_into};
+use crate::clock::{Clock, SystemClock};
+use std::fmt;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use time::OffsetDateTime;
+
+/// Resolution of the timestamps issued by [`MonotonicTimestamps`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Precision {
+    /// Milliseconds: `YYYY-MM-DDTHH:MM:SS.sssZ`.
+    Millis,
+    /// Microseconds: `YYYY-MM-DDTHH:MM:SS.ssssssZ`.
+    Micros,
+    /// Nanoseconds: `YYYY-MM-DDTHH:MM:SS.sssssssssZ`. Limited to instants
+    /// before 2262-04-11, the range of an `i64` nanosecond count.
+    Nanos,
+}
+
+impl Precision {
+    #[inline]
+    const fn nanos_per_unit(self) -> i128 {
+        match self {
+            Self::Millis => 1_000_000,
+            Self::Micros => 1_000,
+            Self::Nanos => 1,
+        }
+    }
+}
+
+/// Thread-safe generator of strictly increasing timestamps.
+///
+/// Every call returns a value strictly greater than every value previously
+/// returned by the same instance, at the configured [`Precision`]. When the
+/// clock has not advanced by at least one unit — several calls within the same
+/// millisecond, or the wall clock stepping backwards after an NTP correction —
+/// the generator issues the previous value plus one unit instead and marks the
+/// result as [nudged](MonotonicTimestamp::was_nudged). Once the clock catches
+/// up, values track it again.
+///
+/// Lock-free: the last issued value is kept in an `AtomicI64`, like
+/// [`RequestIdGenerator`](crate::request_id::RequestIdGenerator)'s counter.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::{MonotonicTimestamps, Precision};
+///
+/// static STAMPS: MonotonicTimestamps = MonotonicTimestamps::new(Precision::Micros);
+///
+/// let a = STAMPS.next();
+/// let b = STAMPS.next();
+/// assert!(a < b);
+/// assert!(a.to_string() < b.to_string());
+/// ```
+pub struct MonotonicTimestamps {
+    last: AtomicI64,
+    nudges: AtomicU64,
+    precision: Precision,
+}
+
+impl MonotonicTimestamps {
+    /// Create a generator issuing timestamps at `precision`.
+    pub const fn new(precision: Precision) -> Self {
+        Self {
+            last: AtomicI64::new(i64::MIN),
+            nudges: AtomicU64::new(0),
+            precision,
+        }
+    }
+
+    /// The precision of issued timestamps.
+    pub const fn precision(&self) -> Precision {
+        self.precision
+    }
+
+    /// Issue the next timestamp from the system clock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock reports a time outside the representable range
+    /// (see [`Precision::Nanos`]).
+    #[inline]
+    pub fn next(&self) -> MonotonicTimestamp {
+        self.next_with_clock(&SystemClock)
+    }
+
+    /// Like [`next()`](Self::next), reading the time from `clock`.
+    pub fn next_with_clock<C: Clock + ?Sized>(&self, clock: &C) -> MonotonicTimestamp {
+        let unit = self.precision.nanos_per_unit();
+        let now = i64::try_from(clock.now_utc().unix_timestamp_nanos().div_euclid(unit))
+            .expect("clock time out of range for monotonic timestamps");
+
+        let mut last = self.last.load(Ordering::Relaxed);
+        loop {
+            let candidate = if now > last { now } else { last + 1 };
+            match self.last.compare_exchange_weak(
+                last,
+                candidate,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => {
+                    let nudged = candidate != now;
+                    if nudged {
+                        self.nudges.fetch_add(1, Ordering::Relaxed);
+                    }
+                    // `candidate` is within one unit of a valid clock reading
+                    // or a previously issued value, so it stays in range.
+                    let at = OffsetDateTime::from_unix_timestamp_nanos(candidate as i128 * unit)
+                        .expect("monotonic timestamp out of range");
+                    return MonotonicTimestamp {
+                        at,
+                        nudged,
+                        precision: self.precision,
+                    };
+                }
+                Err(actual) => last = actual,
+            }
+        }
+    }
+
+    /// Total number of timestamps that had to be nudged forward so far.
+    pub fn nudges(&self) -> u64 {
+        self.nudges.load(Ordering::Relaxed)
+    }
+}
+
+/// A timestamp issued by [`MonotonicTimestamps`].
+///
+/// Ordered by instant. Displays in the canonical format at the generator's
+/// precision, so string order matches issue order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MonotonicTimestamp {
+    at: OffsetDateTime,
+    nudged: bool,
+    precision: Precision,
+}
+
+impl MonotonicTimestamp {
+    /// The issued instant (UTC).
+    pub fn datetime(&self) -> OffsetDateTime {
+        self.at
+    }
+
+    /// Whether the generator had to move this value ahead of the clock to
+    /// keep the sequence strictly increasing.
+    pub fn was_nudged(&self) -> bool {
+        self.nudged
+    }
+}
+
+impl From<MonotonicTimestamp> for OffsetDateTime {
+    fn from(ts: MonotonicTimestamp) -> Self {
+        ts.at
+    }
+}
+
+impl fmt::Display for MonotonicTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // All bytes written are ASCII.
+        match self.precision {
+            Precision::Millis => {
+                f.write_str(std::str::from_utf8(&format_utc_millis_into(&self.at)).unwrap())
+            }
+            Precision::Micros => {
+                f.write_str(std::str::from_utf8(&format_utc_micros_into(&self.at)).unwrap())
+            }
+            Precision::Nanos => {
+                f.write_str(std::str::from_utf8(&format_utc_nanos_into(&self.at)).unwrap())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use std::sync::Arc;
+    use time::Duration;
+    use time::macros::datetime;
+
+    #[test]
+    fn test_same_instant_is_nudged() {
+        let clock = MockClock::new(datetime!(2026-10-16 09:00:00 UTC));
+        let stamps = MonotonicTimestamps::new(Precision::Millis);
+        let a = stamps.next_with_clock(&clock);
+        let b = stamps.next_with_clock(&clock);
+        let c = stamps.next_with_clock(&clock);
+        assert!(!a.was_nudged());
+        assert!(b.was_nudged() && c.was_nudged());
+        assert_eq!(a.to_string(), "2026-10-16T09:00:00.000Z");
+        assert_eq!(b.to_string(), "2026-10-16T09:00:00.001Z");
+        assert_eq!(c.to_string(), "2026-10-16T09:00:00.002Z");
+        assert_eq!(stamps.nudges(), 2);
+    }
+
+    #[test]
+    fn test_clock_regression_stays_increasing() {
+        let clock = MockClock::new(datetime!(2026-10-16 09:00:00 UTC));
+        let stamps = MonotonicTimestamps::new(Precision::Micros);
+        let a = stamps.next_with_clock(&clock);
+        clock.advance(Duration::seconds(-5));
+        let b = stamps.next_with_clock(&clock);
+        assert!(b > a);
+        assert!(b.was_nudged());
+        assert_eq!(b.datetime() - a.datetime(), Duration::microseconds(1));
+    }
+
+    #[test]
+    fn test_tracks_clock_once_caught_up() {
+        let clock = MockClock::new(datetime!(2026-10-16 09:00:00 UTC));
+        let stamps = MonotonicTimestamps::new(Precision::Millis);
+        stamps.next_with_clock(&clock);
+        stamps.next_with_clock(&clock);
+        clock.advance(Duration::SECOND);
+        let c = stamps.next_with_clock(&clock);
+        assert!(!c.was_nudged());
+        assert_eq!(c.datetime(), datetime!(2026-10-16 09:00:01 UTC));
+    }
+
+    #[test]
+    fn test_truncates_to_precision() {
+        let clock = MockClock::new(datetime!(2026-10-16 09:00:00.123_456_789 UTC));
+        let stamps = MonotonicTimestamps::new(Precision::Micros);
+        let a = stamps.next_with_clock(&clock);
+        assert_eq!(a.datetime(), datetime!(2026-10-16 09:00:00.123_456 UTC));
+        assert_eq!(a.to_string(), "2026-10-16T09:00:00.123456Z");
+        let stamps = MonotonicTimestamps::new(Precision::Nanos);
+        assert_eq!(
+            stamps.next_with_clock(&clock).to_string(),
+            "2026-10-16T09:00:00.123456789Z"
+        );
+    }
+
+    #[test]
+    fn test_system_clock_strictly_increasing() {
+        let stamps = MonotonicTimestamps::new(Precision::Nanos);
+        let mut prev = stamps.next();
+        for _ in 0..10_000 {
+            let next = stamps.next();
+            assert!(next > prev);
+            prev = next;
+        }
+    }
+
+    #[test]
+    fn test_concurrent_uniqueness() {
+        use std::collections::HashSet;
+        let clock = Arc::new(MockClock::new(datetime!(2026-10-16 09:00:00 UTC)));
+        let stamps = Arc::new(MonotonicTimestamps::new(Precision::Millis));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let clock = Arc::clone(&clock);
+                let stamps = Arc::clone(&stamps);
+                std::thread::spawn(move || {
+                    (0..1000)
+                        .map(|_| stamps.next_with_clock(&*clock).datetime())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        let mut seen = HashSet::new();
+        for h in handles {
+            for at in h.join().unwrap() {
+                assert!(seen.insert(at), "duplicate timestamp {at}");
+            }
+        }
+        assert_eq!(seen.len(), 4000);
+        assert_eq!(stamps.nudges(), 3999);
+    }
+}
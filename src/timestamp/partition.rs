@@ -0,0 +1,380 @@
+//! Time-bucketed partition keys for data lake layouts
+//! (`dt=2026-10-16/hour=09`, `2026/10/16/09`, ...).
+
+use super::{TimestampError, parse_utc_timestamp};
+use std::fmt::Write;
+use time::{Date, Duration, Month, OffsetDateTime, Time, UtcOffset};
+
+// ---------------------------------------------------------------------------
+// Bucket
+// ---------------------------------------------------------------------------
+
+/// Width of a time bucket. All buckets are aligned in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Bucket {
+    /// Whole minutes.
+    Minute,
+    /// Whole hours.
+    Hour,
+    /// Calendar days.
+    Day,
+    /// ISO 8601 weeks, starting Monday 00:00 UTC.
+    Week,
+    /// Calendar months.
+    Month,
+}
+
+impl Bucket {
+    /// Truncate `dt` to the start of its bucket (converted to UTC).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kiters::timestamp::Bucket;
+    /// use time::macros::datetime;
+    ///
+    /// let dt = datetime!(2026-10-16 09:41:27.5 UTC);
+    /// assert_eq!(Bucket::Hour.truncate(dt), datetime!(2026-10-16 09:00:00 UTC));
+    /// assert_eq!(Bucket::Week.truncate(dt), datetime!(2026-10-12 00:00:00 UTC));
+    /// ```
+    pub fn truncate(self, dt: OffsetDateTime) -> OffsetDateTime {
+        let dt = dt.to_offset(UtcOffset::UTC);
+        let date = dt.date();
+        match self {
+            Self::Minute => dt.replace_time(Time::from_hms(dt.hour(), dt.minute(), 0).unwrap()),
+            Self::Hour => dt.replace_time(Time::from_hms(dt.hour(), 0, 0).unwrap()),
+            Self::Day => date.midnight().assume_utc(),
+            Self::Week => {
+                let back = date.weekday().number_days_from_monday() as i64;
+                // Falls back to the earliest representable date at the range edge.
+                date.checked_sub(Duration::days(back))
+                    .unwrap_or(Date::MIN)
+                    .midnight()
+                    .assume_utc()
+            }
+            Self::Month => date.replace_day(1).unwrap().midnight().assume_utc(),
+        }
+    }
+
+    /// Start of the bucket following the one `start` belongs to, or `None` on
+    /// overflow.
+    pub fn next(self, start: OffsetDateTime) -> Option<OffsetDateTime> {
+        let start = self.truncate(start);
+        match self {
+            Self::Minute => start.checked_add(Duration::MINUTE),
+            Self::Hour => start.checked_add(Duration::HOUR),
+            Self::Day => start.checked_add(Duration::DAY),
+            Self::Week => start.checked_add(Duration::WEEK),
+            Self::Month => {
+                let (year, month) = match start.month() {
+                    Month::December => (start.year().checked_add(1)?, Month::January),
+                    m => (start.year(), m.next()),
+                };
+                Some(
+                    Date::from_calendar_date(year, month, 1)
+                        .ok()?
+                        .midnight()
+                        .assume_utc(),
+                )
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// PartitionStyle / Partitioner
+// ---------------------------------------------------------------------------
+
+/// How a partition key is rendered. Examples are for an event at
+/// `2026-10-16T09:41:27Z` with [`Bucket::Hour`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PartitionStyle {
+    /// `dt=2026-10-16/hour=09`. For week and month buckets the `dt` is the
+    /// first day of the bucket.
+    HiveDate,
+    /// `year=2026/month=10/day=16/hour=09`. Week buckets render as
+    /// `year=2026/week=42` using the ISO week-numbering year.
+    Hive,
+    /// `2026/10/16/09`. Week buckets render as `2026/W42`.
+    Path,
+}
+
+/// Renders partition keys for a fixed bucket width and style.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::{Bucket, PartitionStyle, Partitioner};
+/// use time::macros::datetime;
+///
+/// let hive = Partitioner::new(Bucket::Hour, PartitionStyle::HiveDate);
+/// assert_eq!(hive.key(datetime!(2026-10-16 09:41:27 UTC)), "dt=2026-10-16/hour=09");
+///
+/// let path = Partitioner::new(Bucket::Hour, PartitionStyle::Path);
+/// assert_eq!(path.key_from_str("2026-10-16T09:41:27Z").unwrap(), "2026/10/16/09");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Partitioner {
+    bucket: Bucket,
+    style: PartitionStyle,
+}
+
+impl Partitioner {
+    /// Create a partitioner for the given bucket width and rendering style.
+    pub const fn new(bucket: Bucket, style: PartitionStyle) -> Self {
+        Self { bucket, style }
+    }
+
+    /// The bucket width.
+    pub const fn bucket(&self) -> Bucket {
+        self.bucket
+    }
+
+    /// The rendering style.
+    pub const fn style(&self) -> PartitionStyle {
+        self.style
+    }
+
+    /// Render the partition key for the bucket containing `dt`.
+    pub fn key(&self, dt: OffsetDateTime) -> String {
+        let start = self.bucket.truncate(dt);
+        let (y, m, d) = (start.year(), start.month() as u8, start.day());
+        let (h, min) = (start.hour(), start.minute());
+        let mut out = String::with_capacity(40);
+
+        // Writing to a String never fails.
+        match (self.style, self.bucket) {
+            (PartitionStyle::HiveDate, Bucket::Month | Bucket::Week | Bucket::Day) => {
+                write!(out, "dt={y:04}-{m:02}-{d:02}").unwrap();
+            }
+            (PartitionStyle::HiveDate, Bucket::Hour) => {
+                write!(out, "dt={y:04}-{m:02}-{d:02}/hour={h:02}").unwrap();
+            }
+            (PartitionStyle::HiveDate, Bucket::Minute) => {
+                write!(out, "dt={y:04}-{m:02}-{d:02}/hour={h:02}/minute={min:02}").unwrap();
+            }
+            (PartitionStyle::Hive, Bucket::Week) => {
+                let (iso_year, week, _) = start.to_iso_week_date();
+                write!(out, "year={iso_year:04}/week={week:02}").unwrap();
+            }
+            (PartitionStyle::Hive, bucket) => {
+                write!(out, "year={y:04}/month={m:02}").unwrap();
+                if bucket <= Bucket::Day {
+                    write!(out, "/day={d:02}").unwrap();
+                }
+                if bucket <= Bucket::Hour {
+                    write!(out, "/hour={h:02}").unwrap();
+                }
+                if bucket == Bucket::Minute {
+                    write!(out, "/minute={min:02}").unwrap();
+                }
+            }
+            (PartitionStyle::Path, Bucket::Week) => {
+                let (iso_year, week, _) = start.to_iso_week_date();
+                write!(out, "{iso_year:04}/W{week:02}").unwrap();
+            }
+            (PartitionStyle::Path, bucket) => {
+                write!(out, "{y:04}/{m:02}").unwrap();
+                if bucket <= Bucket::Day {
+                    write!(out, "/{d:02}").unwrap();
+                }
+                if bucket <= Bucket::Hour {
+                    write!(out, "/{h:02}").unwrap();
+                }
+                if bucket == Bucket::Minute {
+                    write!(out, "/{min:02}").unwrap();
+                }
+            }
+        }
+        out
+    }
+
+    /// Render the partition key for a canonical `YYYY-MM-DDTHH:MM:SSZ` string.
+    pub fn key_from_str(&self, s: &str) -> Result<String, TimestampError> {
+        parse_utc_timestamp(s).map(|dt| self.key(dt))
+    }
+
+    /// Iterate over the starts of every bucket overlapping `[start, end)`.
+    ///
+    /// The first item is `start` truncated to its bucket. Empty if
+    /// `end <= start`.
+    pub fn buckets(&self, start: OffsetDateTime, end: OffsetDateTime) -> Buckets {
+        Buckets {
+            bucket: self.bucket,
+            next: (start < end).then(|| self.bucket.truncate(start)),
+            end: end.to_offset(UtcOffset::UTC),
+        }
+    }
+
+    /// Iterate over the partition keys of every bucket overlapping `[start, end)`,
+    /// e.g. for backfills.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kiters::timestamp::{Bucket, PartitionStyle, Partitioner};
+    /// use time::macros::datetime;
+    ///
+    /// let p = Partitioner::new(Bucket::Day, PartitionStyle::Path);
+    /// let keys: Vec<_> = p
+    ///     .keys(datetime!(2026-10-30 12:00 UTC), datetime!(2026-11-02 00:00 UTC))
+    ///     .collect();
+    /// assert_eq!(keys, ["2026/10/30", "2026/10/31", "2026/11/01"]);
+    /// ```
+    pub fn keys(
+        &self,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> impl Iterator<Item = String> + '_ {
+        self.buckets(start, end).map(|b| self.key(b))
+    }
+}
+
+/// Iterator over bucket start times, returned by [`Partitioner::buckets`].
+#[derive(Debug, Clone)]
+pub struct Buckets {
+    bucket: Bucket,
+    next: Option<OffsetDateTime>,
+    end: OffsetDateTime,
+}
+
+impl Iterator for Buckets {
+    type Item = OffsetDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.filter(|&t| t < self.end)?;
+        self.next = self.bucket.next(current);
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    const EVENT: OffsetDateTime = datetime!(2026-10-16 09:41:27.5 UTC);
+
+    #[test]
+    fn test_truncate_all_buckets() {
+        assert_eq!(
+            Bucket::Minute.truncate(EVENT),
+            datetime!(2026-10-16 09:41 UTC)
+        );
+        assert_eq!(
+            Bucket::Hour.truncate(EVENT),
+            datetime!(2026-10-16 09:00 UTC)
+        );
+        assert_eq!(Bucket::Day.truncate(EVENT), datetime!(2026-10-16 00:00 UTC));
+        assert_eq!(
+            Bucket::Week.truncate(EVENT),
+            datetime!(2026-10-12 00:00 UTC)
+        );
+        assert_eq!(
+            Bucket::Month.truncate(EVENT),
+            datetime!(2026-10-01 00:00 UTC)
+        );
+    }
+
+    #[test]
+    fn test_truncate_converts_to_utc() {
+        let dt = datetime!(2026-10-16 01:30 +03:00);
+        assert_eq!(Bucket::Day.truncate(dt), datetime!(2026-10-15 00:00 UTC));
+    }
+
+    #[test]
+    fn test_next_month_rolls_over_year() {
+        assert_eq!(
+            Bucket::Month.next(datetime!(2026-12-15 00:00 UTC)),
+            Some(datetime!(2027-01-01 00:00 UTC))
+        );
+    }
+
+    #[test]
+    fn test_hive_date_keys() {
+        let key = |b| Partitioner::new(b, PartitionStyle::HiveDate).key(EVENT);
+        assert_eq!(key(Bucket::Minute), "dt=2026-10-16/hour=09/minute=41");
+        assert_eq!(key(Bucket::Hour), "dt=2026-10-16/hour=09");
+        assert_eq!(key(Bucket::Day), "dt=2026-10-16");
+        assert_eq!(key(Bucket::Week), "dt=2026-10-12");
+        assert_eq!(key(Bucket::Month), "dt=2026-10-01");
+    }
+
+    #[test]
+    fn test_hive_keys() {
+        let key = |b| Partitioner::new(b, PartitionStyle::Hive).key(EVENT);
+        assert_eq!(
+            key(Bucket::Minute),
+            "year=2026/month=10/day=16/hour=09/minute=41"
+        );
+        assert_eq!(key(Bucket::Hour), "year=2026/month=10/day=16/hour=09");
+        assert_eq!(key(Bucket::Day), "year=2026/month=10/day=16");
+        assert_eq!(key(Bucket::Week), "year=2026/week=42");
+        assert_eq!(key(Bucket::Month), "year=2026/month=10");
+    }
+
+    #[test]
+    fn test_path_keys() {
+        let key = |b| Partitioner::new(b, PartitionStyle::Path).key(EVENT);
+        assert_eq!(key(Bucket::Minute), "2026/10/16/09/41");
+        assert_eq!(key(Bucket::Hour), "2026/10/16/09");
+        assert_eq!(key(Bucket::Day), "2026/10/16");
+        assert_eq!(key(Bucket::Week), "2026/W42");
+        assert_eq!(key(Bucket::Month), "2026/10");
+    }
+
+    #[test]
+    fn test_week_uses_iso_year() {
+        // 2027-01-01 is a Friday in ISO week 53 of 2026.
+        let p = Partitioner::new(Bucket::Week, PartitionStyle::Path);
+        assert_eq!(p.key(datetime!(2027-01-01 12:00 UTC)), "2026/W53");
+    }
+
+    #[test]
+    fn test_key_from_str() {
+        let p = Partitioner::new(Bucket::Hour, PartitionStyle::HiveDate);
+        assert_eq!(
+            p.key_from_str("2026-10-16T09:41:27Z").unwrap(),
+            "dt=2026-10-16/hour=09"
+        );
+        assert!(p.key_from_str("2026-10-16 09:41:27").is_err());
+    }
+
+    #[test]
+    fn test_bucket_iteration() {
+        let p = Partitioner::new(Bucket::Hour, PartitionStyle::Path);
+        let keys: Vec<_> = p
+            .keys(
+                datetime!(2026-10-16 22:30 UTC),
+                datetime!(2026-10-17 01:00 UTC),
+            )
+            .collect();
+        assert_eq!(keys, ["2026/10/16/22", "2026/10/16/23", "2026/10/17/00"]);
+    }
+
+    #[test]
+    fn test_bucket_iteration_partial_last_bucket() {
+        let p = Partitioner::new(Bucket::Month, PartitionStyle::Path);
+        let starts: Vec<_> = p
+            .buckets(
+                datetime!(2026-11-15 00:00 UTC),
+                datetime!(2027-01-01 00:00:01 UTC),
+            )
+            .collect();
+        assert_eq!(
+            starts,
+            [
+                datetime!(2026-11-01 00:00 UTC),
+                datetime!(2026-12-01 00:00 UTC),
+                datetime!(2027-01-01 00:00 UTC),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bucket_iteration_empty_range() {
+        let p = Partitioner::new(Bucket::Day, PartitionStyle::Path);
+        assert_eq!(p.buckets(EVENT, EVENT).count(), 0);
+        assert_eq!(p.buckets(EVENT, EVENT - Duration::DAY).count(), 0);
+    }
+}
@@ -0,0 +1,316 @@
+//! Human-relative rendering of timestamps: `3 minutes ago`, `in 2 days`, or
+//! compact `3m` / `2d` for terminal tables.
+
+use crate::clock::{Clock, SystemClock};
+use time::{Duration, OffsetDateTime};
+
+/// Output style of a [`RelativeFormatter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RelativeStyle {
+    /// `3 minutes ago`, `in 2 days`, `just now`.
+    #[default]
+    Long,
+    /// `3m`, `in 2d`, `now`.
+    Compact,
+}
+
+#[derive(Clone, Copy)]
+enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
+}
+
+impl Unit {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Second => "second",
+            Self::Minute => "minute",
+            Self::Hour => "hour",
+            Self::Day => "day",
+            Self::Month => "month",
+            Self::Year => "year",
+        }
+    }
+
+    const fn suffix(self) -> &'static str {
+        match self {
+            Self::Second => "s",
+            Self::Minute => "m",
+            Self::Hour => "h",
+            Self::Day => "d",
+            Self::Month => "mo",
+            Self::Year => "y",
+        }
+    }
+}
+
+/// Renders an `OffsetDateTime` relative to a reference "now".
+///
+/// The distance is expressed in the largest unit whose threshold it reaches,
+/// rounded down: 3 minutes 59 seconds is `3 minutes ago`. Each `with_max_*`
+/// threshold is the count at which a unit gives way to the next one, once
+/// the distance amounts to at least one of the next unit — with
+/// `with_max_days(7)`, 7 to 29 days stay in days. Months count as 30 days and
+/// years as 365 days. Distances below the
+/// [just-now window](Self::with_just_now) render as `just now` (`now` in
+/// compact style).
+///
+/// | Threshold     | Default | Meaning                                    |
+/// |---------------|---------|--------------------------------------------|
+/// | just now      | 1s      | below this, `just now`                     |
+/// | `max_seconds` | 60      | below 60 seconds, count seconds            |
+/// | `max_minutes` | 60      | below 60 minutes, count minutes            |
+/// | `max_hours`   | 24      | below 24 hours, count hours                |
+/// | `max_days`    | 30      | below 30 days, count days                  |
+/// | `max_months`  | 12      | below 12 months, count months; else years  |
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::{RelativeFormatter, RelativeStyle};
+/// use time::macros::datetime;
+///
+/// let now = datetime!(2026-10-16 09:00:00 UTC);
+/// let fmt = RelativeFormatter::new();
+/// assert_eq!(fmt.format_at(datetime!(2026-10-16 08:57:00 UTC), now), "3 minutes ago");
+/// assert_eq!(fmt.format_at(datetime!(2026-10-18 09:00:00 UTC), now), "in 2 days");
+///
+/// let compact = RelativeFormatter::new().with_style(RelativeStyle::Compact);
+/// assert_eq!(compact.format_at(datetime!(2026-10-16 08:57:00 UTC), now), "3m");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RelativeFormatter {
+    style: RelativeStyle,
+    just_now: Duration,
+    max_seconds: u32,
+    max_minutes: u32,
+    max_hours: u32,
+    max_days: u32,
+    max_months: u32,
+}
+
+impl Default for RelativeFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RelativeFormatter {
+    /// Create a formatter with the default thresholds and long style.
+    pub const fn new() -> Self {
+        Self {
+            style: RelativeStyle::Long,
+            just_now: Duration::SECOND,
+            max_seconds: 60,
+            max_minutes: 60,
+            max_hours: 24,
+            max_days: 30,
+            max_months: 12,
+        }
+    }
+
+    /// Set the output style.
+    pub const fn with_style(mut self, style: RelativeStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the window, on either side of now, rendered as `just now`.
+    /// `Duration::ZERO` disables it.
+    pub const fn with_just_now(mut self, window: Duration) -> Self {
+        self.just_now = window;
+        self
+    }
+
+    /// Set the number of seconds from which minutes are shown instead.
+    pub const fn with_max_seconds(mut self, max: u32) -> Self {
+        self.max_seconds = max;
+        self
+    }
+
+    /// Set the number of minutes from which hours are shown instead.
+    pub const fn with_max_minutes(mut self, max: u32) -> Self {
+        self.max_minutes = max;
+        self
+    }
+
+    /// Set the number of hours from which days are shown instead.
+    pub const fn with_max_hours(mut self, max: u32) -> Self {
+        self.max_hours = max;
+        self
+    }
+
+    /// Set the number of days from which months are shown instead.
+    pub const fn with_max_days(mut self, max: u32) -> Self {
+        self.max_days = max;
+        self
+    }
+
+    /// Set the number of months from which years are shown instead.
+    pub const fn with_max_months(mut self, max: u32) -> Self {
+        self.max_months = max;
+        self
+    }
+
+    /// Render `dt` relative to the system clock.
+    pub fn format(&self, dt: OffsetDateTime) -> String {
+        self.format_with_clock(dt, &SystemClock)
+    }
+
+    /// Render `dt` relative to the time read from `clock`.
+    pub fn format_with_clock<C: Clock + ?Sized>(&self, dt: OffsetDateTime, clock: &C) -> String {
+        self.format_at(dt, clock.now_utc())
+    }
+
+    /// Render `dt` relative to `now`.
+    pub fn format_at(&self, dt: OffsetDateTime, now: OffsetDateTime) -> String {
+        let delta = dt - now;
+        let future = delta.is_positive();
+        let distance = delta.abs();
+        if distance < self.just_now {
+            return match self.style {
+                RelativeStyle::Long => "just now".to_owned(),
+                RelativeStyle::Compact => "now".to_owned(),
+            };
+        }
+
+        let (count, unit) = self.pick_unit(distance.whole_seconds().unsigned_abs());
+        match (self.style, future) {
+            (RelativeStyle::Long, false) => format!("{count} {} ago", plural(count, unit)),
+            (RelativeStyle::Long, true) => format!("in {count} {}", plural(count, unit)),
+            (RelativeStyle::Compact, false) => format!("{count}{}", unit.suffix()),
+            (RelativeStyle::Compact, true) => format!("in {count}{}", unit.suffix()),
+        }
+    }
+
+    fn pick_unit(&self, seconds: u64) -> (u64, Unit) {
+        let days = seconds / 86_400;
+        let units = [
+            (seconds, Unit::Second, self.max_seconds),
+            (seconds / 60, Unit::Minute, self.max_minutes),
+            (seconds / 3_600, Unit::Hour, self.max_hours),
+            (days, Unit::Day, self.max_days),
+            (days / 30, Unit::Month, self.max_months),
+        ];
+        for (i, &(count, unit, max)) in units.iter().enumerate() {
+            let next = units.get(i + 1).map_or(days / 365, |&(next, _, _)| next);
+            // Stay in this unit below its cap, or when the next unit would
+            // round the distance down to zero.
+            if count < max as u64 || next == 0 {
+                return (count, unit);
+            }
+        }
+        (days / 365, Unit::Year)
+    }
+}
+
+fn plural(count: u64, unit: Unit) -> String {
+    if count == 1 {
+        unit.name().to_owned()
+    } else {
+        format!("{}s", unit.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::FixedClock;
+    use time::macros::datetime;
+
+    const NOW: OffsetDateTime = datetime!(2026-10-16 09:00:00 UTC);
+
+    fn long(offset: Duration) -> String {
+        RelativeFormatter::new().format_at(NOW + offset, NOW)
+    }
+
+    fn compact(offset: Duration) -> String {
+        RelativeFormatter::new()
+            .with_style(RelativeStyle::Compact)
+            .format_at(NOW + offset, NOW)
+    }
+
+    #[test]
+    fn test_long_past_and_future() {
+        assert_eq!(long(Duration::ZERO), "just now");
+        assert_eq!(long(Duration::milliseconds(-999)), "just now");
+        assert_eq!(long(Duration::seconds(-1)), "1 second ago");
+        assert_eq!(long(Duration::seconds(-59)), "59 seconds ago");
+        assert_eq!(long(Duration::seconds(-239)), "3 minutes ago");
+        assert_eq!(long(Duration::minutes(60)), "in 1 hour");
+        assert_eq!(long(Duration::hours(-23)), "23 hours ago");
+        assert_eq!(long(Duration::days(2)), "in 2 days");
+        assert_eq!(long(Duration::days(-45)), "1 month ago");
+        assert_eq!(long(Duration::days(400)), "in 1 year");
+        assert_eq!(long(Duration::days(-1000)), "2 years ago");
+    }
+
+    #[test]
+    fn test_compact() {
+        assert_eq!(compact(Duration::ZERO), "now");
+        assert_eq!(compact(Duration::seconds(-42)), "42s");
+        assert_eq!(compact(Duration::minutes(-3)), "3m");
+        assert_eq!(compact(Duration::hours(5)), "in 5h");
+        assert_eq!(compact(Duration::days(-2)), "2d");
+        assert_eq!(compact(Duration::days(-90)), "3mo");
+        assert_eq!(compact(Duration::days(-730)), "2y");
+    }
+
+    #[test]
+    fn test_custom_thresholds() {
+        let fmt = RelativeFormatter::new()
+            .with_just_now(Duration::seconds(10))
+            .with_max_seconds(90)
+            .with_max_hours(48)
+            .with_max_days(7);
+        assert_eq!(fmt.format_at(NOW - Duration::seconds(9), NOW), "just now");
+        assert_eq!(
+            fmt.format_at(NOW - Duration::seconds(75), NOW),
+            "75 seconds ago"
+        );
+        assert_eq!(
+            fmt.format_at(NOW - Duration::hours(36), NOW),
+            "36 hours ago"
+        );
+        assert_eq!(fmt.format_at(NOW - Duration::days(7), NOW), "7 days ago");
+        assert_eq!(fmt.format_at(NOW - Duration::days(29), NOW), "29 days ago");
+        assert_eq!(fmt.format_at(NOW - Duration::days(30), NOW), "1 month ago");
+
+        let short = RelativeFormatter::new()
+            .with_max_seconds(10)
+            .with_max_months(0);
+        assert_eq!(
+            short.format_at(NOW - Duration::seconds(45), NOW),
+            "45 seconds ago"
+        );
+        assert_eq!(
+            short.format_at(NOW - Duration::days(45), NOW),
+            "1 month ago"
+        );
+        assert_eq!(
+            short.format_at(NOW - Duration::days(400), NOW),
+            "1 year ago"
+        );
+
+        let no_window = RelativeFormatter::new().with_just_now(Duration::ZERO);
+        assert_eq!(no_window.format_at(NOW, NOW), "0 seconds ago");
+    }
+
+    #[test]
+    fn test_pluggable_clock_and_offsets() {
+        let clock = FixedClock::new(NOW);
+        let dt = datetime!(2026-10-16 11:00:00 +02:00) - Duration::minutes(5);
+        assert_eq!(
+            RelativeFormatter::new().format_with_clock(dt, &clock),
+            "5 minutes ago"
+        );
+        assert_eq!(
+            RelativeFormatter::default().format(OffsetDateTime::now_utc()),
+            "just now"
+        );
+    }
+}
@@ -0,0 +1,274 @@
+//! RFC 3339 timestamps with numeric offsets (`2026-10-16T11:00:00+02:00`),
+//! and normalization to the canonical `...Z` form.
+
+use super::{
+    TimestampError, UtcTimestamp, expect_separator, format_utc_into, parse_fraction, parse_hms,
+    parse_offset, parse_ymd,
+};
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+/// Format an `OffsetDateTime` as RFC 3339, keeping its offset.
+///
+/// UTC renders as `Z`; other offsets as `±HH:MM`. Sub-second precision is
+/// dropped, matching the canonical format. Fails with
+/// [`TimestampError::InvalidOffset`] if the offset has a seconds component
+/// (not expressible in RFC 3339), or [`TimestampError::OutOfRange`] if the
+/// local year is outside `0000..=9999`.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::format_rfc3339;
+/// use time::macros::{datetime, offset};
+///
+/// let utc = datetime!(2026-10-16 09:00:00 UTC);
+/// assert_eq!(format_rfc3339(&utc).unwrap(), "2026-10-16T09:00:00Z");
+///
+/// let local = utc.to_offset(offset!(+02:00));
+/// assert_eq!(format_rfc3339(&local).unwrap(), "2026-10-16T11:00:00+02:00");
+/// ```
+pub fn format_rfc3339(dt: &OffsetDateTime) -> Result<String, TimestampError> {
+    let offset = dt.offset();
+    if offset.seconds_past_minute() != 0 {
+        return Err(TimestampError::InvalidOffset);
+    }
+    if !(0..=9999).contains(&dt.year()) {
+        return Err(TimestampError::OutOfRange);
+    }
+    // Lay out the local wall-clock fields, then swap the `Z` for the offset.
+    let buf = format_utc_into(&dt.replace_offset(UtcOffset::UTC));
+    let mut out = String::with_capacity(25);
+    // All bytes written are ASCII.
+    out.push_str(std::str::from_utf8(&buf[..19]).unwrap());
+    if offset.is_utc() {
+        out.push('Z');
+    } else {
+        let sign = if offset.is_negative() { '-' } else { '+' };
+        let (h, m) = (offset.whole_hours().abs(), offset.minutes_past_hour().abs());
+        out.push(sign);
+        out.push_str(&format!("{h:02}:{m:02}"));
+    }
+    Ok(out)
+}
+
+/// Parse an RFC 3339 timestamp, keeping its offset.
+///
+/// Accepts `YYYY-MM-DDTHH:MM:SS[.fraction](Z|±HH:MM)`. As RFC 3339 permits,
+/// `T` and `Z` may be lowercase. Fractions longer than nine digits are
+/// truncated to nanoseconds. `-00:00` ("offset unknown") is read as UTC.
+/// Leap seconds are rejected.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::parse_rfc3339;
+/// use time::macros::offset;
+///
+/// let dt = parse_rfc3339("2026-10-16T11:00:00.5+02:00").unwrap();
+/// assert_eq!(dt.offset(), offset!(+02:00));
+/// assert_eq!(dt.millisecond(), 500);
+/// ```
+pub fn parse_rfc3339(s: &str) -> Result<OffsetDateTime, TimestampError> {
+    let b = s.as_bytes();
+    if b.len() < 20 {
+        return Err(TimestampError::InvalidLength(b.len()));
+    }
+    let date = parse_ymd(b, 0)?;
+    if !matches!(b[10], b'T' | b't') {
+        expect_separator(b, 10, b'T')?;
+    }
+    let mut time = parse_hms(b, 11)?;
+
+    let mut i = 19;
+    if b[i] == b'.' {
+        let start = i + 1;
+        let end = start + b[start..].iter().take_while(|c| c.is_ascii_digit()).count();
+        let nanos = parse_fraction(&b[start..end.min(start + 9)])?;
+        // Range-checked by parse_fraction.
+        time = time.replace_nanosecond(nanos).unwrap();
+        i = end;
+    }
+
+    let offset = parse_offset(&b[i..], false)?;
+    Ok(PrimitiveDateTime::new(date, time).assume_offset(offset))
+}
+
+/// Normalize any RFC 3339 timestamp to the canonical `YYYY-MM-DDTHH:MM:SSZ`
+/// string, i.e. the output of [`get_utc_formatter()`](super::get_utc_formatter).
+///
+/// The instant is converted to UTC and sub-second precision is truncated.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::normalize_to_utc;
+///
+/// assert_eq!(
+///     normalize_to_utc("2026-10-16T11:00:00+02:00").unwrap(),
+///     "2026-10-16T09:00:00Z"
+/// );
+/// ```
+pub fn normalize_to_utc(s: &str) -> Result<String, TimestampError> {
+    let dt = parse_rfc3339(s)?;
+    UtcTimestamp::new(dt).map(|ts| ts.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::{datetime, offset};
+
+    #[test]
+    fn test_format_preserves_offset() {
+        let dt = datetime!(2026-10-16 11:00:00.75 +02:00);
+        assert_eq!(format_rfc3339(&dt).unwrap(), "2026-10-16T11:00:00+02:00");
+        let dt = datetime!(2026-10-16 04:30:00 -04:30);
+        assert_eq!(format_rfc3339(&dt).unwrap(), "2026-10-16T04:30:00-04:30");
+        let dt = datetime!(2026-10-16 09:00:00 UTC);
+        assert_eq!(format_rfc3339(&dt).unwrap(), "2026-10-16T09:00:00Z");
+    }
+
+    #[test]
+    fn test_format_rejects_unrepresentable() {
+        let dt = datetime!(2026-10-16 09:00:00 UTC).to_offset(offset!(+01:00:30));
+        assert_eq!(format_rfc3339(&dt), Err(TimestampError::InvalidOffset));
+        let dt = datetime!(-0001-01-01 00:00:00 UTC);
+        assert_eq!(format_rfc3339(&dt), Err(TimestampError::OutOfRange));
+    }
+
+    #[test]
+    fn test_parse_offsets() {
+        assert_eq!(
+            parse_rfc3339("2026-10-16T11:00:00+02:00"),
+            Ok(datetime!(2026-10-16 11:00:00 +02:00))
+        );
+        assert_eq!(
+            parse_rfc3339("2026-10-16T04:30:00-04:30"),
+            Ok(datetime!(2026-10-16 04:30:00 -04:30))
+        );
+        assert_eq!(
+            parse_rfc3339("2026-10-16t09:00:00z"),
+            Ok(datetime!(2026-10-16 09:00:00 UTC))
+        );
+        assert_eq!(
+            parse_rfc3339("2026-10-16T09:00:00-00:00"),
+            Ok(datetime!(2026-10-16 09:00:00 UTC))
+        );
+    }
+
+    #[test]
+    fn test_parse_fractions() {
+        assert_eq!(
+            parse_rfc3339("2026-10-16T09:00:00.1Z"),
+            Ok(datetime!(2026-10-16 09:00:00.1 UTC))
+        );
+        assert_eq!(
+            parse_rfc3339("2026-10-16T09:00:00.123456789123+01:00"),
+            Ok(datetime!(2026-10-16 09:00:00.123_456_789 +01:00))
+        );
+        assert_eq!(
+            parse_rfc3339("2026-10-16T09:00:00.Z"),
+            Err(TimestampError::InvalidFraction)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_rfc3339("2026-10-16T09:00:00"),
+            Err(TimestampError::InvalidLength(19))
+        );
+        assert_eq!(
+            parse_rfc3339("2026-10-16T09:00:00+0200"),
+            Err(TimestampError::InvalidOffset)
+        );
+        assert_eq!(
+            parse_rfc3339("2026-10-16T09:00:00+24:00"),
+            Err(TimestampError::InvalidOffset)
+        );
+        assert_eq!(
+            parse_rfc3339("2026-10-16T09:00:00Z "),
+            Err(TimestampError::InvalidOffset)
+        );
+        assert_eq!(
+            parse_rfc3339("2026-10-16X09:00:00Z"),
+            Err(TimestampError::InvalidSeparator {
+                index: 10,
+                expected: 'T'
+            })
+        );
+        assert_eq!(
+            parse_rfc3339("2026-02-30T09:00:00Z"),
+            Err(TimestampError::InvalidDay)
+        );
+    }
+
+    #[test]
+    fn test_normalize_to_utc() {
+        assert_eq!(
+            normalize_to_utc("2026-10-16T11:00:00+02:00").unwrap(),
+            "2026-10-16T09:00:00Z"
+        );
+        assert_eq!(
+            normalize_to_utc("2026-12-31T23:30:00.999-01:00").unwrap(),
+            "2027-01-01T00:30:00Z"
+        );
+        assert_eq!(
+            normalize_to_utc("2026-10-16T09:00:00Z").unwrap(),
+            "2026-10-16T09:00:00Z"
+        );
+        assert_eq!(
+            normalize_to_utc("0000-01-01T00:30:00+01:00"),
+            Err(TimestampError::OutOfRange)
+        );
+        assert_eq!(
+            normalize_to_utc("9999-12-31T23:59:59-23:59"),
+            Err(TimestampError::OutOfRange)
+        );
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn format_parse_roundtrip(
+            secs in 0i64..=253_402_200_000,
+            offset_minutes in -1439i32..=1439,
+        ) {
+            let offset = UtcOffset::from_whole_seconds(offset_minutes * 60).unwrap();
+            let dt = OffsetDateTime::from_unix_timestamp(secs).unwrap().to_offset(offset);
+            let s = format_rfc3339(&dt).unwrap();
+            let parsed = parse_rfc3339(&s).unwrap();
+            prop_assert_eq!(parsed, dt);
+            prop_assert_eq!(parsed.offset(), dt.offset());
+        }
+
+        #[test]
+        fn parse_never_panics(s in "\\PC{0,40}") {
+            let _ = parse_rfc3339(&s);
+        }
+
+        #[test]
+        fn normalize_range_edges_never_panic(
+            year in prop_oneof![Just(0u32), Just(9999), 0u32..=9999],
+            month in prop_oneof![Just(1u32), Just(12), 1u32..=12],
+            day in prop_oneof![Just(1u32), Just(31), 1u32..=28],
+            hour in 0u32..24,
+            sign in prop::sample::select(vec!['+', '-']),
+            offset_hours in 0u32..24,
+            offset_minutes in 0u32..60,
+        ) {
+            let s = format!(
+                "{year:04}-{month:02}-{day:02}T{hour:02}:59:59\
+                 {sign}{offset_hours:02}:{offset_minutes:02}"
+            );
+            if let Ok(normalized) = normalize_to_utc(&s) {
+                prop_assert_eq!(normalized.len(), 20);
+            }
+        }
+    }
+}
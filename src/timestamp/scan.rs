@@ -0,0 +1,301 @@
+//! Finding and rewriting timestamps inside free-form text such as log lines,
+//! without a regex engine.
+
+use super::{LenientTimestamp, ParseMode, TimestampVariant, parse_lenient};
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// A timestamp found by [`scan_timestamps`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampMatch<'a> {
+    text: &'a str,
+    range: Range<usize>,
+    timestamp: LenientTimestamp,
+}
+
+impl<'a> TimestampMatch<'a> {
+    /// Byte range of the timestamp within the scanned text.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// The timestamp as written in the text.
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// The parsed timestamp, in UTC.
+    pub fn timestamp(&self) -> &LenientTimestamp {
+        &self.timestamp
+    }
+
+    /// The variant the timestamp was written in.
+    pub fn variant(&self) -> TimestampVariant {
+        self.timestamp.variant()
+    }
+}
+
+/// Iterator over the timestamps in a string; see [`scan_timestamps`].
+#[derive(Debug, Clone)]
+pub struct TimestampScanner<'a> {
+    text: &'a str,
+    pos: usize,
+    mode: ParseMode,
+}
+
+impl<'a> Iterator for TimestampScanner<'a> {
+    type Item = TimestampMatch<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let b = self.text.as_bytes();
+        while self.pos < b.len() {
+            let start = self.pos;
+            if let Some(end) = candidate_end(b, start) {
+                let s = &self.text[start..end];
+                if let Ok(timestamp) = parse_lenient(s, self.mode) {
+                    self.pos = end;
+                    return Some(TimestampMatch {
+                        text: s,
+                        range: start..end,
+                        timestamp,
+                    });
+                }
+            }
+            self.pos += 1;
+        }
+        None
+    }
+}
+
+/// Find every timestamp in `text`, left to right, without overlaps.
+///
+/// Recognizes the date-and-time shapes accepted by
+/// [`parse_lenient`](super::parse_lenient) — `T`, `t` or space separator,
+/// optional seconds and fraction, and `Z`, `z`, a numeric offset or no zone —
+/// when not directly preceded or followed by a letter or digit. Date-only
+/// values are not matched. In [`ParseMode::Strict`], timestamps without a
+/// zone are skipped.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::{ParseMode, scan_timestamps};
+///
+/// let line = "start=2026-10-16 11:00:00+02:00 end=2026-10-16T09:05:00Z ok";
+/// let found: Vec<_> = scan_timestamps(line, ParseMode::Strict).collect();
+/// assert_eq!(found.len(), 2);
+/// assert_eq!(found[0].range(), 6..31);
+/// assert_eq!(found[0].timestamp().canonical(), "2026-10-16T09:00:00Z");
+/// assert_eq!(found[1].as_str(), "2026-10-16T09:05:00Z");
+/// ```
+pub fn scan_timestamps(text: &str, mode: ParseMode) -> TimestampScanner<'_> {
+    TimestampScanner { text, pos: 0, mode }
+}
+
+/// Replace timestamps in `text` in a single pass.
+///
+/// `replace` is called for each match from [`scan_timestamps`]; returning
+/// `Some` substitutes the string, `None` keeps the original. Returns the
+/// input unchanged, without allocating, if nothing was replaced.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::{ParseMode, UtcTimestamp, rewrite_timestamps};
+///
+/// // Shift every timestamp forward by an hour.
+/// let out = rewrite_timestamps("at 2026-10-16T09:00:00Z", ParseMode::Strict, |m| {
+///     let shifted = m.timestamp().datetime() + time::Duration::HOUR;
+///     UtcTimestamp::new(shifted).ok().map(|ts| ts.to_string())
+/// });
+/// assert_eq!(out, "at 2026-10-16T10:00:00Z");
+/// ```
+pub fn rewrite_timestamps<'a, F>(text: &'a str, mode: ParseMode, mut replace: F) -> Cow<'a, str>
+where
+    F: FnMut(&TimestampMatch<'a>) -> Option<String>,
+{
+    let mut out = String::new();
+    let mut copied = 0;
+    for m in scan_timestamps(text, mode) {
+        if let Some(replacement) = replace(&m) {
+            out.push_str(&text[copied..m.range.start]);
+            out.push_str(&replacement);
+            copied = m.range.end;
+        }
+    }
+    if copied == 0 {
+        return Cow::Borrowed(text);
+    }
+    out.push_str(&text[copied..]);
+    Cow::Owned(out)
+}
+
+/// Rewrite every timestamp in `text` to the canonical `YYYY-MM-DDTHH:MM:SSZ`
+/// form.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::{ParseMode, canonicalize_timestamps};
+///
+/// assert_eq!(
+///     canonicalize_timestamps("[2026-10-16 11:00:00.123+0200] GET /", ParseMode::Lenient),
+///     "[2026-10-16T09:00:00Z] GET /"
+/// );
+/// ```
+pub fn canonicalize_timestamps(text: &str, mode: ParseMode) -> Cow<'_, str> {
+    rewrite_timestamps(text, mode, |m| {
+        if m.variant().is_canonical() {
+            None
+        } else {
+            Some(m.timestamp().canonical())
+        }
+    })
+}
+
+/// If a timestamp-shaped token starts at `start`, return where it ends.
+///
+/// Only finds the extent; [`parse_lenient`] validates the values.
+fn candidate_end(b: &[u8], start: usize) -> Option<usize> {
+    let digits = |range: Range<usize>| {
+        b.get(range)
+            .is_some_and(|s| s.iter().all(u8::is_ascii_digit))
+    };
+    let at = |i: usize, set: &[u8]| b.get(i).is_some_and(|c| set.contains(c));
+
+    if start > 0 && b[start - 1].is_ascii_alphanumeric() {
+        return None;
+    }
+    // YYYY-MM-DD?HH:MM
+    if !(digits(start..start + 4)
+        && at(start + 4, b"-")
+        && digits(start + 5..start + 7)
+        && at(start + 7, b"-")
+        && digits(start + 8..start + 10)
+        && at(start + 10, b"Tt ")
+        && digits(start + 11..start + 13)
+        && at(start + 13, b":")
+        && digits(start + 14..start + 16))
+    {
+        return None;
+    }
+    let mut end = start + 16;
+    if at(end, b":") && digits(end + 1..end + 3) {
+        end += 3;
+        if at(end, b".,") && digits(end + 1..end + 2) {
+            end += 1;
+            while digits(end..end + 1) {
+                end += 1;
+            }
+        }
+    }
+    if at(end, b"Zz") {
+        end += 1;
+    } else if at(end, b"+-") && digits(end + 1..end + 3) {
+        if at(end + 3, b":") && digits(end + 4..end + 6) {
+            end += 6;
+        } else if digits(end + 3..end + 5) {
+            end += 5;
+        } else {
+            end += 3;
+        }
+    }
+    if b.get(end).is_some_and(u8::is_ascii_alphanumeric) {
+        return None;
+    }
+    Some(end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn found(text: &str, mode: ParseMode) -> Vec<&str> {
+        scan_timestamps(text, mode).map(|m| m.as_str()).collect()
+    }
+
+    #[test]
+    fn test_scan_finds_all_shapes() {
+        let text = "a 2026-10-16T09:00:00Z b 2026-10-16 09:00 c 2026-10-16t09:00:00.5-04:30, \
+                    d (2026-10-16 09:00:00,25+02) e 2026-10-16T09:00:00+0000.";
+        assert_eq!(
+            found(text, ParseMode::Lenient),
+            [
+                "2026-10-16T09:00:00Z",
+                "2026-10-16 09:00",
+                "2026-10-16t09:00:00.5-04:30",
+                "2026-10-16 09:00:00,25+02",
+                "2026-10-16T09:00:00+0000",
+            ]
+        );
+        assert_eq!(found(text, ParseMode::Strict).len(), 4);
+    }
+
+    #[test]
+    fn test_scan_ranges() {
+        let text = "ts=2026-10-16T09:00:00Z";
+        let m = scan_timestamps(text, ParseMode::Strict).next().unwrap();
+        assert_eq!(m.range(), 3..23);
+        assert_eq!(&text[m.range()], m.as_str());
+    }
+
+    #[test]
+    fn test_scan_skips_non_timestamps() {
+        for text in [
+            "",
+            "2026-10-16",
+            "id12026-10-16T09:00:00Z",
+            "2026-10-16T09:00:00Zulu",
+            "2026-13-16T09:00:00Z",
+            "2026-10-16T25:00:00Z",
+            "version 2026-10-16X09:00",
+            "ünïcödé 20 26-10-16",
+        ] {
+            assert!(found(text, ParseMode::Lenient).is_empty(), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_scan_skips_out_of_range() {
+        // Converts past year 9999 in UTC.
+        let text = "x 9999-12-31T23:59:59-23:59 y 2026-10-16T09:00:00Z";
+        assert_eq!(found(text, ParseMode::Lenient), ["2026-10-16T09:00:00Z"]);
+        assert_eq!(
+            canonicalize_timestamps("x 9999-12-31T23:59:59-23:59 y", ParseMode::Lenient),
+            "x 9999-12-31T23:59:59-23:59 y"
+        );
+    }
+
+    #[test]
+    fn test_scan_handles_adjacent_and_unicode() {
+        let text = "→2026-10-16T09:00:00Z/2026-10-16T10:00:00Z←";
+        assert_eq!(
+            found(text, ParseMode::Strict),
+            ["2026-10-16T09:00:00Z", "2026-10-16T10:00:00Z"]
+        );
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let text = "x 2026-10-16 11:00:00+02:00 y 2026-10-16T09:00:00Z z";
+        assert_eq!(
+            canonicalize_timestamps(text, ParseMode::Strict),
+            "x 2026-10-16T09:00:00Z y 2026-10-16T09:00:00Z z"
+        );
+        let redacted = rewrite_timestamps(text, ParseMode::Strict, |_| Some("<ts>".into()));
+        assert_eq!(redacted, "x <ts> y <ts> z");
+    }
+
+    #[test]
+    fn test_rewrite_borrows_when_unchanged() {
+        let text = "already 2026-10-16T09:00:00Z canonical";
+        assert!(matches!(
+            canonicalize_timestamps(text, ParseMode::Strict),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            rewrite_timestamps("no timestamps", ParseMode::Lenient, |_| Some(String::new())),
+            Cow::Borrowed(_)
+        ));
+    }
+}
@@ -0,0 +1,335 @@
+//! `#[serde(with = ...)]` adapters for `OffsetDateTime` fields.
+//!
+//! - [`utc`]: canonical `YYYY-MM-DDTHH:MM:SSZ` string
+//! - [`utc_option`]: same, for `Option<OffsetDateTime>` (`None` ↔ `null`)
+//! - [`unix_seconds`]: integer seconds since the Unix epoch
+//! - [`unix_millis`]: integer milliseconds since the Unix epoch
+//!
+//! # Example
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use time::OffsetDateTime;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "kiters::timestamp::serde::utc")]
+//!     created_at: OffsetDateTime,
+//!     #[serde(with = "kiters::timestamp::serde::utc_option")]
+//!     deleted_at: Option<OffsetDateTime>,
+//! }
+//!
+//! let json = r#"{"created_at":"2023-10-27T10:00:00Z","deleted_at":null}"#;
+//! let event: Event = serde_json::from_str(json).unwrap();
+//! assert_eq!(event.created_at.unix_timestamp(), 1_698_400_800);
+//! assert_eq!(serde_json::to_string(&event).unwrap(), json);
+//! ```
+
+use super::{format_utc_into, parse_utc_timestamp};
+use ::serde::{Deserialize, Deserializer, Serializer, de, ser};
+use std::fmt;
+use time::{OffsetDateTime, UtcOffset};
+
+/// Encode `dt` canonically, rejecting non-UTC offsets and out-of-range years.
+/// Sub-second precision is truncated.
+fn encode_canonical(dt: &OffsetDateTime) -> Result<[u8; 20], String> {
+    if dt.offset() != UtcOffset::UTC {
+        return Err(format!("timestamp offset must be UTC, got {}", dt.offset()));
+    }
+    if !(0..=9999).contains(&dt.year()) {
+        return Err(format!(
+            "timestamp year {} is outside the canonical range 0000-9999",
+            dt.year()
+        ));
+    }
+    Ok(format_utc_into(dt))
+}
+
+/// Decodes the canonical string, parsing borrowed strings without allocating.
+struct CanonicalVisitor;
+
+impl de::Visitor<'_> for CanonicalVisitor {
+    type Value = OffsetDateTime;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a canonical UTC timestamp")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<OffsetDateTime, E> {
+        parse_utc_timestamp(s)
+            .map_err(|e| E::custom(format_args!("invalid UTC timestamp {s:?}: {e}")))
+    }
+}
+
+/// Like [`CanonicalVisitor`], also accepting `null`.
+struct CanonicalOptionVisitor;
+
+impl<'de> de::Visitor<'de> for CanonicalOptionVisitor {
+    type Value = Option<OffsetDateTime>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a canonical UTC timestamp or null")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_str(CanonicalVisitor).map(Some)
+    }
+}
+
+/// Serialize `OffsetDateTime` as the canonical `YYYY-MM-DDTHH:MM:SSZ` string.
+///
+/// Serialization fails for non-UTC offsets (convert with
+/// `to_offset(UtcOffset::UTC)` first) and years outside `0000..=9999`.
+/// Deserialization is strict, accepting only the canonical form.
+///
+/// # Lossy round trip
+///
+/// The canonical form has whole seconds, so serialization silently truncates
+/// sub-second precision: a value with non-zero nanoseconds deserializes to a
+/// different, earlier value. Truncate before storing (e.g.
+/// `dt.replace_nanosecond(0)`) or use [`UtcTimestamp`](super::UtcTimestamp),
+/// which truncates on construction, where round-trip equality matters.
+pub mod utc {
+    use super::*;
+
+    /// Serialize as the canonical string, truncating sub-second precision.
+    pub fn serialize<S: Serializer>(dt: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error> {
+        let buf = encode_canonical(dt).map_err(ser::Error::custom)?;
+        // All bytes written are ASCII.
+        serializer.serialize_str(std::str::from_utf8(&buf).unwrap())
+    }
+
+    /// Deserialize from the canonical string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<OffsetDateTime, D::Error> {
+        deserializer.deserialize_str(CanonicalVisitor)
+    }
+}
+
+/// Like [`utc`], for `Option<OffsetDateTime>`; `None` maps to `null`.
+pub mod utc_option {
+    use super::*;
+
+    /// Serialize as the canonical string, or `none`.
+    pub fn serialize<S: Serializer>(
+        dt: &Option<OffsetDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match dt {
+            Some(dt) => utc::serialize(dt, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize from the canonical string or `null`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<OffsetDateTime>, D::Error> {
+        deserializer.deserialize_option(CanonicalOptionVisitor)
+    }
+}
+
+/// Serialize `OffsetDateTime` as integer seconds since the Unix epoch.
+///
+/// Any offset is accepted on serialization (the instant is unambiguous);
+/// sub-second precision is truncated toward negative infinity. Deserialized
+/// values are in UTC.
+pub mod unix_seconds {
+    use super::*;
+
+    /// Serialize as whole seconds since the epoch.
+    pub fn serialize<S: Serializer>(dt: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(dt.unix_timestamp())
+    }
+
+    /// Deserialize from whole seconds since the epoch.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<OffsetDateTime, D::Error> {
+        let secs = i64::deserialize(deserializer)?;
+        OffsetDateTime::from_unix_timestamp(secs).map_err(|_| {
+            de::Error::custom(format_args!(
+                "unix timestamp {secs} seconds is out of range"
+            ))
+        })
+    }
+}
+
+/// Serialize `OffsetDateTime` as integer milliseconds since the Unix epoch.
+///
+/// Any offset is accepted on serialization; sub-millisecond precision is
+/// truncated toward negative infinity. Deserialized values are in UTC.
+pub mod unix_millis {
+    use super::*;
+
+    /// Serialize as whole milliseconds since the epoch.
+    pub fn serialize<S: Serializer>(dt: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error> {
+        let millis = dt.unix_timestamp_nanos().div_euclid(1_000_000);
+        // Every representable OffsetDateTime fits in i64 milliseconds.
+        serializer.serialize_i64(millis as i64)
+    }
+
+    /// Deserialize from whole milliseconds since the epoch.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<OffsetDateTime, D::Error> {
+        let millis = i64::deserialize(deserializer)?;
+        OffsetDateTime::from_unix_timestamp_nanos(millis as i128 * 1_000_000).map_err(|_| {
+            de::Error::custom(format_args!(
+                "unix timestamp {millis} milliseconds is out of range"
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+    use time::OffsetDateTime;
+    use time::macros::datetime;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "super::utc")]
+        at: OffsetDateTime,
+        #[serde(with = "super::utc_option")]
+        maybe: Option<OffsetDateTime>,
+        #[serde(with = "super::unix_seconds")]
+        secs: OffsetDateTime,
+        #[serde(with = "super::unix_millis")]
+        millis: OffsetDateTime,
+    }
+
+    #[test]
+    fn test_roundtrip_json() {
+        let record = Record {
+            at: datetime!(2023-10-27 10:00:00 UTC),
+            maybe: Some(datetime!(2023-10-27 10:00:01 UTC)),
+            secs: datetime!(2023-10-27 10:00:02 UTC),
+            millis: datetime!(2023-10-27 10:00:03.250 UTC),
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(
+            json,
+            r#"{"at":"2023-10-27T10:00:00Z","maybe":"2023-10-27T10:00:01Z","secs":1698400802,"millis":1698400803250}"#
+        );
+        let back: Record = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, record);
+    }
+
+    #[test]
+    fn test_option_none_is_null() {
+        let record = Record {
+            at: datetime!(2023-10-27 10:00:00 UTC),
+            maybe: None,
+            secs: datetime!(1970-01-01 00:00:00 UTC),
+            millis: datetime!(1970-01-01 00:00:00 UTC),
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(json.contains(r#""maybe":null"#));
+        let back: Record = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, record);
+    }
+
+    #[test]
+    fn test_utc_serialize_rejects_non_utc_offset() {
+        #[derive(Serialize)]
+        struct W(#[serde(with = "super::utc")] OffsetDateTime);
+        let err = serde_json::to_string(&W(datetime!(2023-10-27 12:00:00 +02:00))).unwrap_err();
+        assert!(err.to_string().contains("offset must be UTC"), "{err}");
+    }
+
+    #[test]
+    fn test_utc_serialize_rejects_out_of_range_year() {
+        #[derive(Serialize)]
+        struct W(#[serde(with = "super::utc")] OffsetDateTime);
+        let err = serde_json::to_string(&W(datetime!(-0001-01-01 00:00:00 UTC))).unwrap_err();
+        assert!(
+            err.to_string().contains("outside the canonical range"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_utc_serialize_truncates_subseconds() {
+        #[derive(Serialize)]
+        struct W(#[serde(with = "super::utc")] OffsetDateTime);
+        let json = serde_json::to_string(&W(datetime!(2023-10-27 10:00:00.999 UTC))).unwrap();
+        assert_eq!(json, r#""2023-10-27T10:00:00Z""#);
+    }
+
+    #[test]
+    fn test_utc_deserialize_rejects_non_canonical() {
+        #[derive(Debug, Deserialize)]
+        struct W(
+            #[serde(with = "super::utc")]
+            #[allow(dead_code)]
+            OffsetDateTime,
+        );
+        let err = serde_json::from_str::<W>(r#""2023-10-27T12:00:00+02:00""#).unwrap_err();
+        assert!(err.to_string().contains("invalid UTC timestamp"), "{err}");
+        let err = serde_json::from_str::<W>(r#""2023-13-27T10:00:00Z""#).unwrap_err();
+        assert!(err.to_string().contains("invalid month"), "{err}");
+    }
+
+    #[test]
+    fn test_deserialize_from_owned_and_non_string_values() {
+        let value = serde_json::json!({
+            "at": "2023-10-27T10:00:00Z",
+            "maybe": "2023-10-27T10:00:01Z",
+            "secs": 0,
+            "millis": 0,
+        });
+        let record: Record = serde_json::from_value(value).unwrap();
+        assert_eq!(record.maybe, Some(datetime!(2023-10-27 10:00:01 UTC)));
+
+        let json = r#"{"at":1698400800,"maybe":null,"secs":0,"millis":0}"#;
+        let err = serde_json::from_str::<Record>(json).unwrap_err();
+        assert!(
+            err.to_string().contains("a canonical UTC timestamp"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_unix_deserialize_out_of_range() {
+        #[derive(Debug, Deserialize)]
+        struct S(
+            #[serde(with = "super::unix_seconds")]
+            #[allow(dead_code)]
+            OffsetDateTime,
+        );
+        #[derive(Debug, Deserialize)]
+        struct M(
+            #[serde(with = "super::unix_millis")]
+            #[allow(dead_code)]
+            OffsetDateTime,
+        );
+        let err = serde_json::from_str::<S>(&i64::MAX.to_string()).unwrap_err();
+        assert!(err.to_string().contains("out of range"), "{err}");
+        let err = serde_json::from_str::<M>(&i64::MIN.to_string()).unwrap_err();
+        assert!(err.to_string().contains("out of range"), "{err}");
+    }
+
+    #[test]
+    fn test_unix_accepts_any_offset_and_floors() {
+        #[derive(Serialize)]
+        struct S(#[serde(with = "super::unix_seconds")] OffsetDateTime);
+        #[derive(Serialize)]
+        struct M(#[serde(with = "super::unix_millis")] OffsetDateTime);
+        let dt = datetime!(1969-12-31 23:59:59.9995 UTC);
+        assert_eq!(serde_json::to_string(&S(dt)).unwrap(), "-1");
+        assert_eq!(serde_json::to_string(&M(dt)).unwrap(), "-1");
+        let local = datetime!(1970-01-01 02:00:00 +02:00);
+        assert_eq!(serde_json::to_string(&S(local)).unwrap(), "0");
+    }
+}
@@ -0,0 +1,391 @@
+//! Named IANA time zones (`America/New_York`), backed by a tz database
+//! embedded in the binary. Requires the `tz` feature.
+//!
+//! Storage stays canonical UTC; these helpers convert at the edges. Local
+//! times that fall in a DST gap or overlap are surfaced as [`LocalResult`]
+//! variants, or resolved by an explicit [`Disambiguation`], never guessed.
+
+use super::{
+    TimestampError, UtcInterval, UtcTimestamp, expect_separator, format_rfc3339, parse_hms,
+    parse_ymd,
+};
+use jiff::tz::{AmbiguousOffset, Offset, TimeZone};
+use time::{Date, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+/// Length of a local wall-clock time, `YYYY-MM-DDTHH:MM:SS`.
+const LOCAL_LEN: usize = 19;
+
+/// How [`NamedZone::from_local`] resolves a local time that occurs zero or
+/// two times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Disambiguation {
+    /// Pick the earlier candidate instant.
+    Earlier,
+    /// Pick the later candidate instant.
+    Later,
+    /// Fail with [`TimestampError::NonexistentLocalTime`] or
+    /// [`TimestampError::AmbiguousLocalTime`].
+    Reject,
+}
+
+/// A local wall-clock time resolved in a [`NamedZone`].
+///
+/// All instants are in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocalResult {
+    /// The local time occurs exactly once.
+    Unique(OffsetDateTime),
+    /// The local time was skipped by a forward transition (spring forward).
+    /// `earlier` reads it with the offset after the transition, `later` with
+    /// the offset before; both are outside the gap.
+    Gap {
+        /// Candidate instant before the transition.
+        earlier: OffsetDateTime,
+        /// Candidate instant after the transition.
+        later: OffsetDateTime,
+    },
+    /// The local time occurs twice because of a backward transition (fall
+    /// back).
+    Ambiguous {
+        /// First occurrence.
+        earlier: OffsetDateTime,
+        /// Second occurrence.
+        later: OffsetDateTime,
+    },
+}
+
+impl LocalResult {
+    /// Resolve to a single instant according to `disambiguation`.
+    pub fn resolve(self, disambiguation: Disambiguation) -> Result<OffsetDateTime, TimestampError> {
+        match (self, disambiguation) {
+            (Self::Unique(dt), _) => Ok(dt),
+            (Self::Gap { .. }, Disambiguation::Reject) => Err(TimestampError::NonexistentLocalTime),
+            (Self::Ambiguous { .. }, Disambiguation::Reject) => {
+                Err(TimestampError::AmbiguousLocalTime)
+            }
+            (
+                Self::Gap { earlier, .. } | Self::Ambiguous { earlier, .. },
+                Disambiguation::Earlier,
+            ) => Ok(earlier),
+            (Self::Gap { later, .. } | Self::Ambiguous { later, .. }, Disambiguation::Later) => {
+                Ok(later)
+            }
+        }
+    }
+}
+
+/// An IANA time zone from the embedded tz database.
+///
+/// # Example
+///
+/// ```
+/// use kiters::timestamp::{Disambiguation, NamedZone, UtcTimestamp};
+///
+/// let ny = NamedZone::get("America/New_York").unwrap();
+///
+/// let ts: UtcTimestamp = "2026-07-01T16:00:00Z".parse().unwrap();
+/// assert_eq!(ny.format_local(ts).unwrap(), "2026-07-01T12:00:00-04:00");
+///
+/// let back = ny.parse_local("2026-07-01T12:00:00", Disambiguation::Reject).unwrap();
+/// assert_eq!(back, ts);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NamedZone {
+    name: String,
+    tz: TimeZone,
+}
+
+impl NamedZone {
+    /// Look up a zone by IANA name, e.g. `Europe/Berlin`. Fails with
+    /// [`TimestampError::UnknownZone`] if the name is not in the database.
+    pub fn get(name: &str) -> Result<Self, TimestampError> {
+        let tz = TimeZone::get(name).map_err(|_| TimestampError::UnknownZone)?;
+        let name = tz.iana_name().unwrap_or(name).to_owned();
+        Ok(Self { name, tz })
+    }
+
+    /// The IANA name of the zone.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// UTC offset in effect in this zone at the instant `dt`.
+    pub fn offset_at(&self, dt: OffsetDateTime) -> Result<UtcOffset, TimestampError> {
+        // `Timestamp::new` range-checks the seconds; `from_nanosecond` only
+        // debug-asserts them.
+        let ts = jiff::Timestamp::new(dt.unix_timestamp(), dt.nanosecond() as i32)
+            .map_err(|_| TimestampError::OutOfRange)?;
+        to_utc_offset(self.tz.to_offset(ts))
+    }
+
+    /// The instant `dt` expressed in this zone's local time.
+    pub fn to_local(&self, dt: OffsetDateTime) -> Result<OffsetDateTime, TimestampError> {
+        dt.checked_to_offset(self.offset_at(dt)?)
+            .ok_or(TimestampError::OutOfRange)
+    }
+
+    /// Resolve a local wall-clock time to the UTC instant(s) it denotes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kiters::timestamp::{LocalResult, NamedZone};
+    /// use time::macros::datetime;
+    ///
+    /// let ny = NamedZone::get("America/New_York").unwrap();
+    /// // 02:30 on the spring-forward day does not exist.
+    /// assert_eq!(
+    ///     ny.local(datetime!(2026-03-08 02:30:00)).unwrap(),
+    ///     LocalResult::Gap {
+    ///         earlier: datetime!(2026-03-08 06:30:00 UTC),
+    ///         later: datetime!(2026-03-08 07:30:00 UTC),
+    ///     }
+    /// );
+    /// ```
+    pub fn local(&self, local: PrimitiveDateTime) -> Result<LocalResult, TimestampError> {
+        let civil = jiff::civil::DateTime::new(
+            i16::try_from(local.year()).map_err(|_| TimestampError::OutOfRange)?,
+            local.month() as i8,
+            local.day() as i8,
+            local.hour() as i8,
+            local.minute() as i8,
+            local.second() as i8,
+            local.nanosecond() as i32,
+        )
+        .map_err(|_| TimestampError::OutOfRange)?;
+        let at = |offset: Offset| -> Result<OffsetDateTime, TimestampError> {
+            local
+                .assume_offset(to_utc_offset(offset)?)
+                .checked_to_offset(UtcOffset::UTC)
+                .ok_or(TimestampError::OutOfRange)
+        };
+        Ok(match self.tz.to_ambiguous_timestamp(civil).offset() {
+            AmbiguousOffset::Unambiguous { offset } => LocalResult::Unique(at(offset)?),
+            AmbiguousOffset::Gap { before, after } => {
+                let (a, b) = (at(before)?, at(after)?);
+                LocalResult::Gap {
+                    earlier: a.min(b),
+                    later: a.max(b),
+                }
+            }
+            AmbiguousOffset::Fold { before, after } => {
+                let (a, b) = (at(before)?, at(after)?);
+                LocalResult::Ambiguous {
+                    earlier: a.min(b),
+                    later: a.max(b),
+                }
+            }
+        })
+    }
+
+    /// Resolve a local wall-clock time to a single UTC instant.
+    pub fn from_local(
+        &self,
+        local: PrimitiveDateTime,
+        disambiguation: Disambiguation,
+    ) -> Result<OffsetDateTime, TimestampError> {
+        self.local(local)?.resolve(disambiguation)
+    }
+
+    /// Render a canonical UTC timestamp as RFC 3339 in this zone's local
+    /// time, e.g. `2026-07-01T12:00:00-04:00`.
+    pub fn format_local(&self, ts: UtcTimestamp) -> Result<String, TimestampError> {
+        format_rfc3339(&self.to_local(ts.as_offset_date_time())?)
+    }
+
+    /// Parse a local wall-clock time `YYYY-MM-DDTHH:MM:SS` (no offset) in
+    /// this zone and return the canonical UTC timestamp.
+    pub fn parse_local(
+        &self,
+        s: &str,
+        disambiguation: Disambiguation,
+    ) -> Result<UtcTimestamp, TimestampError> {
+        let b = s.as_bytes();
+        if b.len() != LOCAL_LEN {
+            return Err(TimestampError::InvalidLength(b.len()));
+        }
+        let date = parse_ymd(b, 0)?;
+        expect_separator(b, 10, b'T')?;
+        let time = parse_hms(b, 11)?;
+        UtcTimestamp::new(self.from_local(PrimitiveDateTime::new(date, time), disambiguation)?)
+    }
+
+    /// First instant of the local calendar day `date`.
+    ///
+    /// If midnight falls in a gap, the day starts at the transition; if it
+    /// occurs twice, at its first occurrence.
+    pub fn start_of_day(&self, date: Date) -> Result<OffsetDateTime, TimestampError> {
+        match self.local(date.midnight())? {
+            LocalResult::Unique(dt) => Ok(dt),
+            // The gap's later candidate reads midnight with the pre-transition
+            // offset, which is exactly the transition instant.
+            LocalResult::Gap { later, .. } => Ok(later),
+            LocalResult::Ambiguous { earlier, .. } => Ok(earlier),
+        }
+    }
+
+    /// The local calendar day `date` as a half-open UTC interval — 23, 24 or
+    /// 25 hours long around DST transitions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kiters::timestamp::NamedZone;
+    /// use time::macros::date;
+    ///
+    /// let ny = NamedZone::get("America/New_York").unwrap();
+    /// let day = ny.day(date!(2026-11-01)).unwrap();
+    /// assert_eq!(day.to_string(), "2026-11-01T04:00:00Z/2026-11-02T05:00:00Z");
+    /// assert_eq!(day.duration(), time::Duration::hours(25));
+    /// ```
+    pub fn day(&self, date: Date) -> Result<UtcInterval, TimestampError> {
+        let next = date.next_day().ok_or(TimestampError::OutOfRange)?;
+        UtcInterval::new(
+            UtcTimestamp::new(self.start_of_day(date)?)?,
+            UtcTimestamp::new(self.start_of_day(next)?)?,
+        )
+    }
+}
+
+impl PartialEq for NamedZone {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for NamedZone {}
+
+fn to_utc_offset(offset: Offset) -> Result<UtcOffset, TimestampError> {
+    UtcOffset::from_whole_seconds(offset.seconds()).map_err(|_| TimestampError::InvalidOffset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Duration;
+    use time::macros::{date, datetime, offset};
+
+    fn ny() -> NamedZone {
+        NamedZone::get("America/New_York").unwrap()
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(ny().name(), "America/New_York");
+        assert_eq!(
+            NamedZone::get("Mars/Olympus_Mons"),
+            Err(TimestampError::UnknownZone)
+        );
+    }
+
+    #[test]
+    fn test_to_local_tracks_dst() {
+        let zone = ny();
+        assert_eq!(
+            zone.to_local(datetime!(2026-01-15 17:00:00 UTC)).unwrap(),
+            datetime!(2026-01-15 12:00:00 -05:00)
+        );
+        assert_eq!(
+            zone.offset_at(datetime!(2026-07-01 12:00:00 UTC)).unwrap(),
+            offset!(-04:00)
+        );
+    }
+
+    #[test]
+    fn test_gap() {
+        let zone = ny();
+        let local = datetime!(2026-03-08 02:30:00);
+        assert_eq!(
+            zone.from_local(local, Disambiguation::Reject),
+            Err(TimestampError::NonexistentLocalTime)
+        );
+        assert_eq!(
+            zone.from_local(local, Disambiguation::Later).unwrap(),
+            datetime!(2026-03-08 07:30:00 UTC)
+        );
+    }
+
+    #[test]
+    fn test_overlap() {
+        let zone = ny();
+        let local = datetime!(2026-11-01 01:30:00);
+        assert_eq!(
+            zone.local(local).unwrap(),
+            LocalResult::Ambiguous {
+                earlier: datetime!(2026-11-01 05:30:00 UTC),
+                later: datetime!(2026-11-01 06:30:00 UTC),
+            }
+        );
+        assert_eq!(
+            zone.parse_local("2026-11-01T01:30:00", Disambiguation::Reject),
+            Err(TimestampError::AmbiguousLocalTime)
+        );
+        assert_eq!(
+            zone.parse_local("2026-11-01T01:30:00", Disambiguation::Earlier)
+                .unwrap()
+                .to_string(),
+            "2026-11-01T05:30:00Z"
+        );
+    }
+
+    #[test]
+    fn test_day_boundaries() {
+        let zone = ny();
+        let day = zone.day(date!(2026 - 03 - 08)).unwrap();
+        assert_eq!(day.duration(), Duration::hours(23));
+        assert_eq!(day.to_string(), "2026-03-08T05:00:00Z/2026-03-09T04:00:00Z");
+
+        // Santiago springs forward at midnight: the day starts at 01:00 local.
+        let santiago = NamedZone::get("America/Santiago").unwrap();
+        let start = santiago.start_of_day(date!(2026 - 09 - 06)).unwrap();
+        assert_eq!(start, datetime!(2026-09-06 04:00:00 UTC));
+        assert_eq!(
+            santiago.to_local(start).unwrap(),
+            datetime!(2026-09-06 01:00:00 -03:00)
+        );
+    }
+
+    #[test]
+    fn test_parse_local_errors() {
+        assert_eq!(
+            ny().parse_local("2026-07-01T12:00:00Z", Disambiguation::Reject),
+            Err(TimestampError::InvalidLength(20))
+        );
+        assert_eq!(
+            ny().parse_local("2026-07-01 12:00:00", Disambiguation::Reject),
+            Err(TimestampError::InvalidSeparator {
+                index: 10,
+                expected: 'T'
+            })
+        );
+    }
+
+    #[test]
+    fn test_range_edges() {
+        // West of UTC: the last local hour of year 9999 is in year 10000 UTC.
+        assert_eq!(
+            ny().parse_local("9999-12-31T23:00:00", Disambiguation::Reject),
+            Err(TimestampError::OutOfRange)
+        );
+        assert_eq!(
+            ny().local(datetime!(9999-12-31 23:00:00)),
+            Err(TimestampError::OutOfRange)
+        );
+        assert_eq!(
+            ny().offset_at(datetime!(9999-12-31 23:00:00 UTC)),
+            Err(TimestampError::OutOfRange)
+        );
+        // East of UTC: the last UTC hour of year 9999 is local year 10000.
+        let tokyo = NamedZone::get("Asia/Tokyo").unwrap();
+        let last = UtcTimestamp::new(datetime!(9999-12-31 23:00:00 UTC)).unwrap();
+        assert_eq!(tokyo.format_local(last), Err(TimestampError::OutOfRange));
+        assert_eq!(
+            tokyo.to_local(datetime!(9999-12-31 23:00:00 UTC)),
+            Err(TimestampError::OutOfRange)
+        );
+        assert_eq!(
+            tokyo.parse_local("0000-01-01T08:00:00", Disambiguation::Reject),
+            Err(TimestampError::OutOfRange)
+        );
+    }
+}